@@ -16,6 +16,7 @@ mod oauth2 {
     pub mod client_credentials;
     pub mod refresh_token;
     pub mod authorize;
+    pub mod device;
 }
 mod compute {
     pub mod hmac;
@@ -35,6 +36,10 @@ pub use crate::actions::oauth2::{
     client_credentials::OAuth2ClientCredentialsHandler,
     refresh_token::OAuth2RefreshTokenHandler,
     authorize::OAuth2AwaitCallbackHandler,
+    device::{
+        begin_device_auth, poll_device_auth, DeviceAuthSession, DevicePollOutcome,
+        OAuth2DeviceAuthorizeHandler, OAuth2DevicePollHandler,
+    },
 };
 #[cfg(feature = "vault")]
 pub use crate::actions::secrets::VaultSecretsProvider;
@@ -68,6 +73,9 @@ impl TaskHandler for DefaultRouter {
             "oauth2.await_callback" => {
                 OAuth2AwaitCallbackHandler.execute(resource, state_name, ctx)
             }
+            "oauth2.device_authorize" => {
+                OAuth2DeviceAuthorizeHandler.execute(resource, state_name, ctx)
+            }
 
             // Inject
             "inject.bearer" => InjectBearerHandler.execute(resource, state_name, ctx),
@@ -94,8 +102,8 @@ impl TaskHandler for DefaultRouter {
                 anyhow::bail!("Connection operations require a connection store. Use a custom router with ConnectionStore support.")
             }
             
-            // Explicitly unsupported in default router to avoid hidden state deps  
-            "ensure.fresh_token" => {
+            // Explicitly unsupported in default router to avoid hidden state deps
+            "ensure.fresh_token" | "oauth2.device_poll" => {
                 anyhow::bail!("stateful action '{resource}' requires a custom router")
             }
 
@@ -214,6 +222,12 @@ impl TaskHandler for ActionRouter {
                 Ok(serde_json::to_value(conn)?)
             }
 
+            // Stateful: oauth2.device_poll
+            "oauth2.device_poll" => {
+                let handler = OAuth2DevicePollHandler { store: self.connection_store.clone() };
+                handler.execute(resource, state_name, ctx)
+            }
+
             // Fallback to stateless default router
             _ => self.default_router.execute(resource, state_name, ctx),
         }
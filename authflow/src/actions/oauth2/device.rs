@@ -0,0 +1,208 @@
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::engine::TaskHandler;
+use crate::store::{AuthConnectionTrn, Connection, ConnectionStore};
+
+/// Session state returned by `begin_device_auth`: the device/user code pair to show the operator,
+/// plus everything `poll_device_auth` needs to keep polling the token endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceAuthSession {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    pub verification_uri_complete: Option<String>,
+    pub interval_secs: u64,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Result of a single `poll_device_auth` attempt. `Pending`/`SlowDown` carry the interval the
+/// caller should wait before polling again, matching RFC 8628's `authorization_pending` /
+/// `slow_down` responses; the caller is expected to re-poll in a loop (or, inside the engine, to
+/// return control and be re-scheduled) until `Complete` or an error.
+#[derive(Debug)]
+pub enum DevicePollOutcome {
+    Pending { interval_secs: u64 },
+    SlowDown { interval_secs: u64 },
+    Complete(Connection),
+}
+
+/// Requests a device code + user code from `device_authorization_endpoint` (RFC 8628 section 3.1/3.2).
+pub async fn begin_device_auth(
+    device_authorization_endpoint: &str,
+    client_id: &str,
+    scope: Option<&str>,
+) -> Result<DeviceAuthSession> {
+    let mut form = vec![("client_id", client_id)];
+    if let Some(s) = scope {
+        form.push(("scope", s));
+    }
+    let resp = reqwest::Client::new()
+        .post(device_authorization_endpoint)
+        .form(&form)
+        .send()
+        .await?;
+    if !resp.status().is_success() {
+        return Err(anyhow!("device authorization request failed: {}", resp.status()));
+    }
+    let payload: Value = resp.json().await?;
+    let device_code = payload
+        .get("device_code")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow!("missing device_code"))?
+        .to_string();
+    let user_code = payload
+        .get("user_code")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow!("missing user_code"))?
+        .to_string();
+    let verification_uri = payload
+        .get("verification_uri")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow!("missing verification_uri"))?
+        .to_string();
+    let verification_uri_complete =
+        payload.get("verification_uri_complete").and_then(|v| v.as_str()).map(|s| s.to_string());
+    let interval_secs = payload.get("interval").and_then(|v| v.as_u64()).unwrap_or(5);
+    let expires_in = payload.get("expires_in").and_then(|v| v.as_i64()).unwrap_or(1800);
+
+    Ok(DeviceAuthSession {
+        device_code,
+        user_code,
+        verification_uri,
+        verification_uri_complete,
+        interval_secs,
+        expires_at: Utc::now() + chrono::Duration::seconds(expires_in),
+    })
+}
+
+/// Makes a single token-endpoint poll (RFC 8628 section 3.4/3.5) and, on success, builds the resulting
+/// `Connection` for `trn`. Does not persist it or sleep between attempts - callers drive the
+/// retry loop themselves, waiting `interval_secs` between `Pending`/`SlowDown` outcomes and
+/// stopping once `session.expires_at` has passed.
+pub async fn poll_device_auth(
+    token_endpoint: &str,
+    client_id: &str,
+    client_secret: Option<&str>,
+    session: &DeviceAuthSession,
+    trn: &AuthConnectionTrn,
+) -> Result<DevicePollOutcome> {
+    if Utc::now() > session.expires_at {
+        return Err(anyhow!("device code expired before authorization completed"));
+    }
+
+    let mut form = vec![
+        ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+        ("device_code", session.device_code.as_str()),
+        ("client_id", client_id),
+    ];
+    if let Some(cs) = client_secret {
+        form.push(("client_secret", cs));
+    }
+
+    let resp = reqwest::Client::new().post(token_endpoint).form(&form).send().await?;
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        if body.contains("authorization_pending") {
+            return Ok(DevicePollOutcome::Pending { interval_secs: session.interval_secs });
+        }
+        if body.contains("slow_down") {
+            // RFC 8628 section 3.5: back off by 5 seconds on every slow_down response.
+            return Ok(DevicePollOutcome::SlowDown { interval_secs: session.interval_secs + 5 });
+        }
+        return Err(anyhow!("token polling failed: {} - {}", status, body));
+    }
+
+    let token_json: Value = resp.json().await?;
+    let access_token = token_json
+        .get("access_token")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow!("missing access_token"))?;
+    let refresh_token = token_json.get("refresh_token").and_then(|v| v.as_str());
+    let expires_in = token_json.get("expires_in").and_then(|v| v.as_i64());
+    let token_type = token_json.get("token_type").and_then(|v| v.as_str()).unwrap_or("Bearer");
+    let scope = token_json.get("scope").and_then(|v| v.as_str());
+
+    let mut connection =
+        Connection::new(trn.tenant.clone(), trn.provider.clone(), trn.user_id.clone(), access_token)?;
+    connection.token_type = token_type.to_string();
+    if let Some(rt) = refresh_token {
+        connection = connection.with_refresh_token(rt);
+    }
+    if let Some(exp) = expires_in {
+        connection = connection.with_expires_in(exp);
+    }
+    if let Some(s) = scope {
+        connection = connection.with_scope(s);
+    }
+    connection.extra = token_json.clone();
+
+    Ok(DevicePollOutcome::Complete(connection))
+}
+
+/// Stateless engine handler for `oauth2.device_authorize` - starts a device-authorization grant.
+#[derive(Default)]
+pub struct OAuth2DeviceAuthorizeHandler;
+
+impl TaskHandler for OAuth2DeviceAuthorizeHandler {
+    fn execute(&self, _resource: &str, _state_name: &str, ctx: &Value) -> Result<Value> {
+        let endpoint = ctx
+            .get("deviceAuthorizationEndpoint")
+            .and_then(|v| v.as_str())
+            .context("deviceAuthorizationEndpoint required")?;
+        let client_id = ctx.get("clientId").and_then(|v| v.as_str()).context("clientId required")?;
+        let scope = ctx.get("scope").and_then(|v| v.as_str());
+
+        let session = futures::executor::block_on(begin_device_auth(endpoint, client_id, scope))?;
+        Ok(serde_json::to_value(session)?)
+    }
+}
+
+/// Stateful engine handler for `oauth2.device_poll` - polls the token endpoint once and, on
+/// success, persists the resulting `Connection` via the injected store. Wired through
+/// `ActionRouter` (not `DefaultRouter`) since it needs a `ConnectionStore`, same as
+/// `connection.update`/`ensure.fresh_token`.
+pub struct OAuth2DevicePollHandler {
+    pub store: std::sync::Arc<dyn ConnectionStore>,
+}
+
+impl TaskHandler for OAuth2DevicePollHandler {
+    fn execute(&self, _resource: &str, _state_name: &str, ctx: &Value) -> Result<Value> {
+        let token_endpoint =
+            ctx.get("tokenEndpoint").and_then(|v| v.as_str()).context("tokenEndpoint required")?;
+        let client_id = ctx.get("clientId").and_then(|v| v.as_str()).context("clientId required")?;
+        let client_secret = ctx.get("clientSecret").and_then(|v| v.as_str());
+        let session: DeviceAuthSession = serde_json::from_value(
+            ctx.get("session").cloned().context("session required")?,
+        )?;
+        let tenant = ctx.get("tenant").and_then(|v| v.as_str()).context("tenant required")?;
+        let provider = ctx.get("provider").and_then(|v| v.as_str()).context("provider required")?;
+        let user_id = ctx.get("userId").and_then(|v| v.as_str()).context("userId required")?;
+        let trn = AuthConnectionTrn::new(tenant, provider, user_id)?;
+
+        let outcome = futures::executor::block_on(poll_device_auth(
+            token_endpoint,
+            client_id,
+            client_secret,
+            &session,
+            &trn,
+        ))?;
+
+        match outcome {
+            DevicePollOutcome::Pending { interval_secs } => {
+                Ok(json!({ "status": "authorization_pending", "interval_secs": interval_secs }))
+            }
+            DevicePollOutcome::SlowDown { interval_secs } => {
+                Ok(json!({ "status": "slow_down", "interval_secs": interval_secs }))
+            }
+            DevicePollOutcome::Complete(connection) => {
+                let trn_key = connection.connection_id();
+                futures::executor::block_on(self.store.put(&trn_key, &connection))?;
+                Ok(json!({ "status": "complete", "trn": trn_key }))
+            }
+        }
+    }
+}
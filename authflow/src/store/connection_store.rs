@@ -394,6 +394,58 @@ impl ConnectionStore for MemoryConnectionStore {
     }
 }
 
+/// Refresh `connection_ref`'s tokens if they expire within `skew` of now, using
+/// `ConnectionStore::compare_and_swap` to give single-flight semantics across processes sharing
+/// the same store: only the caller whose CAS wins actually installs a new token, and everyone
+/// else re-reads and returns whatever that winner stored instead of refreshing again.
+///
+/// `refresher` is handed the current `Connection` and must return the refreshed one (typically by
+/// exchanging `refresh_token` with the provider's token endpoint).
+pub async fn refresh_if_expiring<F, Fut>(
+    store: &dyn ConnectionStore,
+    connection_ref: &str,
+    skew: Duration,
+    refresher: F,
+) -> Result<Connection>
+where
+    F: Fn(Connection) -> Fut,
+    Fut: std::future::Future<Output = Result<Connection>>,
+{
+    const MAX_ATTEMPTS: u32 = 5;
+    let skew = chrono::Duration::from_std(skew).unwrap_or_else(|_| chrono::Duration::zero());
+
+    for _ in 0..MAX_ATTEMPTS {
+        let current = store
+            .get(connection_ref)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("connection not found: {}", connection_ref))?;
+
+        let needs_refresh = current
+            .expires_at
+            .map(|exp| exp <= Utc::now() + skew)
+            .unwrap_or(false);
+        if !needs_refresh {
+            return Ok(current);
+        }
+
+        let refreshed = refresher(current.clone()).await?;
+        if store
+            .compare_and_swap(connection_ref, Some(&current), Some(&refreshed))
+            .await?
+        {
+            return Ok(refreshed);
+        }
+        // Lost the race to another refresher; loop around and re-read the winning value.
+    }
+
+    // Another task keeps winning the CAS faster than we can observe a settled value; return
+    // whatever is currently stored rather than retrying indefinitely.
+    store
+        .get(connection_ref)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("connection not found: {}", connection_ref))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -474,6 +526,52 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_refresh_if_expiring_skips_fresh_connection() {
+        let store = MemoryConnectionStore::new().without_ttl();
+        let conn = Connection::new("test_tenant", "github", "user1", "token1")
+            .unwrap()
+            .with_expires_in(3600);
+        let conn_id = conn.connection_id();
+        store.put(&conn_id, &conn).await.unwrap();
+
+        let refreshed = refresh_if_expiring(
+            &store,
+            &conn_id,
+            Duration::from_secs(60),
+            |_current| async { panic!("refresher should not run for a fresh connection") },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(refreshed.access_token, "token1");
+    }
+
+    #[tokio::test]
+    async fn test_refresh_if_expiring_refreshes_expiring_connection() {
+        let store = MemoryConnectionStore::new().without_ttl();
+        let conn = Connection::new("test_tenant", "github", "user1", "token1")
+            .unwrap()
+            .with_expires_in(30);
+        let conn_id = conn.connection_id();
+        store.put(&conn_id, &conn).await.unwrap();
+
+        let refreshed = refresh_if_expiring(
+            &store,
+            &conn_id,
+            Duration::from_secs(300),
+            |current| async move { Ok(current.with_expires_in(3600)) },
+        )
+        .await
+        .unwrap();
+
+        assert!(refreshed.expires_at.unwrap() > Utc::now() + chrono::Duration::minutes(30));
+        assert_eq!(
+            store.get(&conn_id).await.unwrap().unwrap().access_token,
+            refreshed.access_token
+        );
+    }
+
     #[tokio::test]
     async fn test_memory_store_ttl() {
         let store = MemoryConnectionStore::new().with_default_ttl(Duration::from_millis(100));
@@ -35,6 +35,10 @@ pub struct ServeMcpArgs {
     /// Governance preset: quick allow/deny configuration
     #[arg(long, value_enum, help = "Governance preset: a-only|b-only|mixed")]
     pub preset: Option<GovernancePreset>,
+
+    /// Expose the admin.connection.* and admin.oauth2.device_* admin tools (off by default)
+    #[arg(long, help = "Expose the admin.connection.* and admin.oauth2.device_* admin tools")]
+    pub enable_admin_tools: bool,
 }
 
 #[derive(Copy, Clone, Debug, ValueEnum)]
@@ -62,7 +66,8 @@ pub async fn execute(args: ServeMcpArgs, db_path: &str) -> Result<()> {
     };
 
     // Create governance configuration
-    let governance = GovernanceConfig::new(allow, deny, args.max_concurrency, args.timeout_secs);
+    let governance = GovernanceConfig::new(allow, deny, args.max_concurrency, args.timeout_secs)
+        .with_admin_enabled(args.enable_admin_tools);
 
     // Create app state
     let app_state = AppState::from_db_path(db_path).await?;
@@ -1,6 +1,6 @@
 use crate::app::service::OpenActService;
 use crate::interface::dto::AdhocExecuteRequestDto;
-use crate::models::common::RetryPolicy;
+use crate::models::common::{RetryBackoff, RetryPolicy};
 use crate::models::{ConnectionConfig, TaskConfig};
 use crate::store::ConnectionStore;
 use crate::store::{DatabaseManager, StorageService};
@@ -59,6 +59,12 @@ pub struct ExecuteOverrides {
     /// Override retry policy: aggressive|conservative|custom
     #[arg(long)]
     pub retry_policy: Option<String>,
+    /// Override retry backoff strategy: none|exponential|exponential-jitter
+    #[arg(long)]
+    pub retry_backoff: Option<String>,
+    /// Override the ceiling for backoff delays in milliseconds (used with --retry-backoff)
+    #[arg(long)]
+    pub retry_max_delay_ms: Option<u64>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -258,6 +264,37 @@ pub enum ConfigCmd {
         #[arg(long)]
         format: Option<String>,
     },
+    /// Export connections/tasks as an encrypted, portable backup archive
+    Backup {
+        /// Output archive path
+        #[arg(long)]
+        out: PathBuf,
+        /// Name of the environment variable holding the encryption passphrase
+        #[arg(long)]
+        passphrase_env: String,
+    },
+    /// Restore connections/tasks from a `config backup` archive
+    Restore {
+        /// Input archive path
+        #[arg(long = "in")]
+        input: PathBuf,
+        /// Name of the environment variable holding the decryption passphrase
+        #[arg(long)]
+        passphrase_env: String,
+        /// Report what would be imported without writing anything
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+    },
+    /// Watch connection/task file(s) and live-reload them as they change on disk
+    Watch {
+        #[arg(long)]
+        connections: Option<PathBuf>,
+        #[arg(long)]
+        tasks: Option<PathBuf>,
+        /// Debounce window for coalescing rapid filesystem events
+        #[arg(long, default_value_t = 300)]
+        debounce_ms: u64,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -272,6 +309,12 @@ pub enum SystemCmd {
         #[arg(long)]
         yes: bool,
     },
+    /// Expose the same data as `stats` in an exposition format suitable for scraping
+    Metrics {
+        /// Output format: prometheus (default) or json
+        #[arg(long, default_value = "prometheus")]
+        format: String,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -367,7 +410,11 @@ pub enum TemplateTaskCmd {
 
 #[derive(Subcommand, Debug)]
 pub enum OauthCmd {
-    /// Start Authorization Code flow (prints authorize_url/state/code_verifier)
+    /// Start Authorization Code flow (prints authorize_url/state/code_verifier). Not available
+    /// in `--server` mode: the DSL workflow run state lives only in this process, which a remote
+    /// server has no API to expose (unlike `bind`, which is a single stateless PUT the server can
+    /// proxy). This is a deliberate scope cut, not an oversight - `--server` parity for `oauth`
+    /// covers `bind` only.
     Start {
         /// DSL YAML file path
         #[arg(short, long)]
@@ -376,7 +423,8 @@ pub enum OauthCmd {
         #[arg(long, default_value_t = false)]
         open_browser: bool,
     },
-    /// Resume with code/state
+    /// Resume with code/state. Not available in `--server` mode, for the same reason as `start`:
+    /// the paused run state from `start` lives only in this process.
     Resume {
         /// DSL YAML file path
         #[arg(short, long)]
@@ -399,38 +447,57 @@ pub enum OauthCmd {
         /// auth connection TRN
         auth_trn: String,
     },
-    /// OAuth 2.0 Device Code (RFC 8628) flow
+    /// OAuth 2.0 Device Authorization Grant (RFC 8628) flow. Endpoints and client/tenant
+    /// identity can be passed as flags or, for headless hosts that would rather not repeat
+    /// them on the command line, loaded from a `--dsl` YAML file (flags take precedence).
     DeviceCode {
-        /// Token endpoint URL
+        /// Token endpoint URL (required unless provided via --dsl)
         #[arg(long)]
-        token_url: String,
-        /// Device authorization endpoint URL
+        token_url: Option<String>,
+        /// Device authorization endpoint URL (required unless provided via --dsl)
         #[arg(long)]
-        device_code_url: String,
-        /// OAuth2 client_id
+        device_code_url: Option<String>,
+        /// OAuth2 client_id (required unless provided via --dsl)
         #[arg(long)]
-        client_id: String,
+        client_id: Option<String>,
         /// OAuth2 client_secret (optional)
         #[arg(long)]
         client_secret: Option<String>,
         /// Scope (optional, space-separated)
         #[arg(long)]
         scope: Option<String>,
-        /// Tenant for storing credentials
+        /// Tenant for storing credentials (required unless provided via --dsl)
         #[arg(long)]
-        tenant: String,
-        /// Provider name for auth record (e.g., github)
+        tenant: Option<String>,
+        /// Provider name for auth record, e.g. github (required unless provided via --dsl)
         #[arg(long)]
-        provider: String,
-        /// User identifier used to build auth record TRN
+        provider: Option<String>,
+        /// User identifier used to build auth record TRN (required unless provided via --dsl)
         #[arg(long)]
-        user_id: String,
+        user_id: Option<String>,
+        /// DSL YAML file describing the device/token endpoints, client credentials and the
+        /// tenant/provider/user_id used to name the resulting auth connection
+        #[arg(short, long)]
+        dsl: Option<std::path::PathBuf>,
         /// Optionally bind to a connection TRN after success
         #[arg(long)]
         bind_connection: Option<String>,
     },
 }
 
+/// Provider configuration for `oauth device-code --dsl`, parsed from the DSL file
+#[derive(Debug, serde::Deserialize)]
+struct DeviceAuthDsl {
+    device_authorization_endpoint: String,
+    token_endpoint: String,
+    client_id: String,
+    client_secret: Option<String>,
+    scope: Option<String>,
+    tenant: String,
+    provider: String,
+    user_id: String,
+}
+
 pub async fn run(cli: Cli) -> Result<()> {
     // Initialize OpenAct service (prefer explicit db_url)
     let service = if let Some(db) = &cli.db_url {
@@ -1313,14 +1380,47 @@ pub async fn run(cli: Cli) -> Result<()> {
                     let s = std::fs::read_to_string(p)?;
                     tsk = parse_json_or_yaml(&s)?;
                 }
+                if let Some(base) = &cli.server {
+                    let body = server_request(
+                        base,
+                        reqwest::Method::POST,
+                        "/api/v1/config/import",
+                        &[],
+                        Some(&json!({ "connections": conns, "tasks": tsk })),
+                    )
+                    .await?;
+                    if cli.json {
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&serde_json::from_slice::<
+                                serde_json::Value,
+                            >(&body)?)?
+                        );
+                    } else {
+                        println!("{}", String::from_utf8_lossy(&body));
+                    }
+                    return Ok(());
+                }
                 let (ic, it) = service.import_configurations(conns, tsk).await?;
                 println!("imported: connections={} tasks={}", ic, it);
             }
             ConfigCmd::Export { format } => {
-                let (conns, tasks) = service.export_configurations().await?;
                 let fmt = format
                     .as_deref()
                     .unwrap_or(if cli.json { "json" } else { "yaml" });
+                if let Some(base) = &cli.server {
+                    let body = server_request(
+                        base,
+                        reqwest::Method::GET,
+                        "/api/v1/config/export",
+                        &[("format", fmt)],
+                        None,
+                    )
+                    .await?;
+                    print!("{}", String::from_utf8_lossy(&body));
+                    return Ok(());
+                }
+                let (conns, tasks) = service.export_configurations().await?;
                 match fmt {
                     "json" => {
                         println!(
@@ -1342,9 +1442,72 @@ pub async fn run(cli: Cli) -> Result<()> {
                     other => return Err(anyhow!("unsupported format: {}", other)),
                 }
             }
+            ConfigCmd::Backup { out, passphrase_env } => {
+                let passphrase = std::env::var(passphrase_env).map_err(|_| {
+                    anyhow!("environment variable {} is not set", passphrase_env)
+                })?;
+                let (conns, tasks) = service.export_configurations().await?;
+                let archive = crate::store::backup::encrypt_backup(conns, tasks, &passphrase)?;
+                std::fs::write(out, serde_json::to_vec_pretty(&archive)?)?;
+                println!(
+                    "backed up: connections={} tasks={} -> {}",
+                    archive.connections_count,
+                    archive.tasks_count,
+                    out.display()
+                );
+            }
+            ConfigCmd::Restore {
+                input,
+                passphrase_env,
+                dry_run,
+            } => {
+                let passphrase = std::env::var(passphrase_env).map_err(|_| {
+                    anyhow!("environment variable {} is not set", passphrase_env)
+                })?;
+                let raw = std::fs::read_to_string(input)?;
+                let archive: crate::store::backup::BackupArchive = serde_json::from_str(&raw)?;
+                let (conns, tasks) = crate::store::backup::decrypt_backup(&archive, &passphrase)?;
+                if *dry_run {
+                    println!(
+                        "dry-run: would import connections={} tasks={}",
+                        conns.len(),
+                        tasks.len()
+                    );
+                } else {
+                    let (ic, it) = service.import_configurations(conns, tasks).await?;
+                    println!("restored: connections={} tasks={}", ic, it);
+                }
+            }
+            ConfigCmd::Watch {
+                connections,
+                tasks,
+                debounce_ms,
+            } => {
+                if connections.is_none() && tasks.is_none() {
+                    return Err(anyhow!("provide --connections and/or --tasks"));
+                }
+                watch_config_files(&service, connections.clone(), tasks.clone(), *debounce_ms)
+                    .await?;
+            }
         },
         Commands::System { cmd } => match cmd {
             SystemCmd::Stats => {
+                if let Some(base) = &cli.server {
+                    let body =
+                        server_request(base, reqwest::Method::GET, "/api/v1/system/stats", &[], None)
+                            .await?;
+                    if cli.json {
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&serde_json::from_slice::<
+                                serde_json::Value,
+                            >(&body)?)?
+                        );
+                    } else {
+                        println!("{}", String::from_utf8_lossy(&body));
+                    }
+                    return Ok(());
+                }
                 let stats = service.get_stats().await?;
                 let cache = service.get_cache_stats().await;
                 let cp = crate::executor::client_pool::get_stats();
@@ -1383,7 +1546,69 @@ pub async fn run(cli: Cli) -> Result<()> {
                     );
                 }
             }
+            SystemCmd::Metrics { format } => {
+                if let Some(base) = &cli.server {
+                    let body = server_request(
+                        base,
+                        reqwest::Method::GET,
+                        "/api/v1/system/metrics",
+                        &[("format", format.as_str())],
+                        None,
+                    )
+                    .await?;
+                    print!("{}", String::from_utf8_lossy(&body));
+                } else {
+                    let stats = service.get_stats().await?;
+                    let cache = service.get_cache_stats().await;
+                    let cp = crate::executor::client_pool::get_stats();
+                    match format.as_str() {
+                        "prometheus" => print!("{}", render_prometheus_metrics(&stats, &cache, &cp)),
+                        "json" => {
+                            println!(
+                                "{}",
+                                serde_json::to_string_pretty(&json!({
+                                    "storage": stats,
+                                    "caches": cache,
+                                    "client_pool": {
+                                        "hits": cp.hits,
+                                        "builds": cp.builds,
+                                        "evictions": cp.evictions,
+                                        "size": cp.size,
+                                        "capacity": cp.capacity
+                                    }
+                                }))?
+                            );
+                        }
+                        other => return Err(anyhow!("unsupported metrics format: {}", other)),
+                    }
+                }
+            }
             SystemCmd::Cleanup => {
+                if let Some(base) = &cli.server {
+                    let body = server_request(
+                        base,
+                        reqwest::Method::POST,
+                        "/api/v1/system/cleanup",
+                        &[],
+                        None,
+                    )
+                    .await?;
+                    if cli.json {
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&serde_json::from_slice::<
+                                serde_json::Value,
+                            >(&body)?)?
+                        );
+                    } else {
+                        let r: serde_json::Value = serde_json::from_slice(&body)?;
+                        println!(
+                            "expired_auth_connections: {}",
+                            r.get("cleaned_count").unwrap_or(&json!(0))
+                        );
+                    }
+                    return Ok(());
+                }
                 let r = service.cleanup().await?;
                 if cli.json {
                     println!("{}", serde_json::to_string_pretty(&r)?);
@@ -1449,6 +1674,11 @@ pub async fn run(cli: Cli) -> Result<()> {
         Commands::Oauth { cmd } => {
             match cmd {
                 OauthCmd::Start { dsl, open_browser } => {
+                    if cli.server.is_some() {
+                        return Err(anyhow!(
+                            "oauth start is not available in --server mode: the DSL workflow runs locally against the stored run state, which a remote server does not expose over the admin API"
+                        ));
+                    }
                     let dsl_path = dsl.clone();
                     let yaml = std::fs::read_to_string(dsl)?;
                     let wf: stepflow_dsl::WorkflowDSL = serde_yaml::from_str(&yaml)?;
@@ -1501,6 +1731,11 @@ pub async fn run(cli: Cli) -> Result<()> {
                     state,
                     bind_connection,
                 } => {
+                    if cli.server.is_some() {
+                        return Err(anyhow!(
+                            "oauth resume is not available in --server mode: the DSL workflow run state started by `oauth start` lives only in this process, which a remote server does not expose over the admin API"
+                        ));
+                    }
                     let yaml = std::fs::read_to_string(dsl)?;
                     let dsl: stepflow_dsl::WorkflowDSL = serde_yaml::from_str(&yaml)?;
                     let run_store = crate::store::MemoryRunStore::default();
@@ -1574,6 +1809,24 @@ pub async fn run(cli: Cli) -> Result<()> {
                     connection_trn,
                     auth_trn,
                 } => {
+                    if let Some(base) = &cli.server {
+                        server_request(
+                            base,
+                            reqwest::Method::POST,
+                            "/api/v1/oauth/bind",
+                            &[],
+                            Some(&json!({
+                                "connection_trn": connection_trn,
+                                "auth_trn": auth_trn,
+                            })),
+                        )
+                        .await?;
+                        println!(
+                            "bound: connection={} -> auth_ref={}",
+                            connection_trn, auth_trn
+                        );
+                        return Ok(());
+                    }
                     let manager = service.database();
                     let repo = manager.connection_repository();
                     let mut conn = repo
@@ -1596,20 +1849,62 @@ pub async fn run(cli: Cli) -> Result<()> {
                     tenant,
                     provider,
                     user_id,
+                    dsl,
                     bind_connection,
                 } => {
+                    let cfg = match dsl {
+                        Some(path) => {
+                            let yaml = std::fs::read_to_string(path)?;
+                            Some(serde_yaml::from_str::<DeviceAuthDsl>(&yaml)?)
+                        }
+                        None => None,
+                    };
+                    let device_code_url = device_code_url
+                        .clone()
+                        .or_else(|| cfg.as_ref().map(|c| c.device_authorization_endpoint.clone()))
+                        .ok_or_else(|| anyhow!("--device-code-url or --dsl required"))?;
+                    let token_url = token_url
+                        .clone()
+                        .or_else(|| cfg.as_ref().map(|c| c.token_endpoint.clone()))
+                        .ok_or_else(|| anyhow!("--token-url or --dsl required"))?;
+                    let client_id = client_id
+                        .clone()
+                        .or_else(|| cfg.as_ref().map(|c| c.client_id.clone()))
+                        .ok_or_else(|| anyhow!("--client-id or --dsl required"))?;
+                    let client_secret = client_secret
+                        .clone()
+                        .or_else(|| cfg.as_ref().and_then(|c| c.client_secret.clone()));
+                    let scope = scope
+                        .clone()
+                        .or_else(|| cfg.as_ref().and_then(|c| c.scope.clone()));
+                    let tenant = tenant
+                        .clone()
+                        .or_else(|| cfg.as_ref().map(|c| c.tenant.clone()))
+                        .ok_or_else(|| anyhow!("--tenant or --dsl required"))?;
+                    let provider = provider
+                        .clone()
+                        .or_else(|| cfg.as_ref().map(|c| c.provider.clone()))
+                        .ok_or_else(|| anyhow!("--provider or --dsl required"))?;
+                    let user_id = user_id
+                        .clone()
+                        .or_else(|| cfg.as_ref().map(|c| c.user_id.clone()))
+                        .ok_or_else(|| anyhow!("--user-id or --dsl required"))?;
+
                     // Step 1: device authorization request
                     let mut form = vec![("client_id", client_id.as_str())];
-                    if let Some(s) = scope {
+                    if let Some(s) = &scope {
                         form.push(("scope", s.as_str()));
                     }
                     let resp = reqwest::Client::new()
-                        .post(device_code_url)
+                        .post(&device_code_url)
                         .form(&form)
                         .send()
                         .await?;
                     if !resp.status().is_success() {
-                        return Err(anyhow!("device_code request failed: {}", resp.status()));
+                        return Err(anyhow!(
+                            "device authorization request failed: {}",
+                            resp.status()
+                        ));
                     }
                     let payload: serde_json::Value = resp.json().await?;
                     let device_code = payload
@@ -1625,10 +1920,17 @@ pub async fn run(cli: Cli) -> Result<()> {
                         .and_then(|v| v.as_str())
                         .or_else(|| payload.get("verification_uri").and_then(|v| v.as_str()))
                         .ok_or_else(|| anyhow!("missing verification_uri"))?;
-                    let interval = payload
+                    let mut interval = payload
                         .get("interval")
                         .and_then(|v| v.as_u64())
                         .unwrap_or(5);
+                    let expires_in = payload
+                        .get("expires_in")
+                        .and_then(|v| v.as_u64())
+                        .unwrap_or(900);
+                    let deadline =
+                        tokio::time::Instant::now() + std::time::Duration::from_secs(expires_in);
+
                     if !cli.json {
                         println!("Please open the URL and enter the code:");
                         println!("  {}", verification_uri);
@@ -1638,36 +1940,51 @@ pub async fn run(cli: Cli) -> Result<()> {
                         println!("Polling token endpoint every {}s...", interval);
                     }
 
-                    // Step 2: poll token endpoint
-                    let token_resp = loop {
+                    // Step 2: poll the token endpoint, honoring RFC 8628 error codes
+                    let token_json = loop {
+                        if tokio::time::Instant::now() >= deadline {
+                            return Err(anyhow!("device code expired before authorization"));
+                        }
+
                         let mut form = vec![
                             ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
                             ("device_code", device_code),
                             ("client_id", client_id.as_str()),
                         ];
-                        if let Some(cs) = client_secret {
+                        if let Some(cs) = &client_secret {
                             form.push(("client_secret", cs.as_str()));
                         }
 
                         let r = reqwest::Client::new()
-                            .post(token_url)
+                            .post(&token_url)
                             .form(&form)
                             .send()
                             .await?;
+
                         if r.status().is_success() {
-                            break r;
-                        } else {
-                            let status = r.status();
-                            let body = r.text().await.unwrap_or_default();
-                            if body.contains("authorization_pending") || body.contains("slow_down")
-                            {
+                            break r.json::<serde_json::Value>().await?;
+                        }
+
+                        let body: serde_json::Value = r.json().await.unwrap_or_default();
+                        match body.get("error").and_then(|v| v.as_str()) {
+                            Some("authorization_pending") => {
+                                tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+                            }
+                            Some("slow_down") => {
+                                interval += 5;
                                 tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
-                                continue;
                             }
-                            return Err(anyhow!("token polling failed: {} - {}", status, body));
+                            Some("access_denied") => {
+                                return Err(anyhow!("device authorization was denied by the user"));
+                            }
+                            Some("expired_token") => {
+                                return Err(anyhow!("device code expired before authorization"));
+                            }
+                            Some(other) => return Err(anyhow!("token polling failed: {}", other)),
+                            None => return Err(anyhow!("token polling failed: {}", body)),
                         }
                     };
-                    let token_json: serde_json::Value = token_resp.json().await?;
+
                     let access_token = token_json
                         .get("access_token")
                         .and_then(|v| v.as_str())
@@ -1677,25 +1994,32 @@ pub async fn run(cli: Cli) -> Result<()> {
                         .get("refresh_token")
                         .and_then(|v| v.as_str())
                         .map(|s| s.to_string());
-                    let expires_in = token_json
+                    let token_expires_in = token_json
                         .get("expires_in")
                         .and_then(|v| v.as_i64())
                         .unwrap_or(3600);
+                    let token_type = token_json
+                        .get("token_type")
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string());
                     let scope_val = token_json
                         .get("scope")
                         .and_then(|v| v.as_str())
-                        .map(|s| s.to_string());
-                    let expires_at = chrono::Utc::now() + chrono::Duration::seconds(expires_in);
+                        .map(|s| s.to_string())
+                        .or(scope);
+                    let expires_at =
+                        chrono::Utc::now() + chrono::Duration::seconds(token_expires_in);
 
-                    // Step 3: persist as AuthConnection
+                    // Step 3: persist as AuthConnection; the optional bind below sets auth_ref
+                    // and upserts the connection exactly as Resume/Bind do
                     let ac = crate::models::AuthConnection::new_with_params(
-                        tenant.clone(),
-                        provider.clone(),
-                        user_id.clone(),
+                        tenant,
+                        provider,
+                        user_id,
                         access_token,
                         refresh_token,
                         Some(expires_at),
-                        Some("Bearer".to_string()),
+                        token_type,
                         scope_val,
                         None,
                     )?;
@@ -1703,15 +2027,14 @@ pub async fn run(cli: Cli) -> Result<()> {
                     let storage = service.storage();
                     storage.put(&trn_str, &ac).await?;
                     if !cli.json {
-                        println!("✅ Device code flow completed. auth_trn: {}", trn_str);
+                        println!("✅ Device authorization flow completed. auth_trn: {}", trn_str);
                     }
 
-                    // Optional bind to connection
                     if let Some(conn_trn) = bind_connection {
                         let manager = service.database();
                         let repo = manager.connection_repository();
                         let mut conn = repo
-                            .get_by_trn(&conn_trn)
+                            .get_by_trn(conn_trn)
                             .await?
                             .ok_or_else(|| anyhow!("connection not found: {}", conn_trn))?;
                         conn.auth_ref = Some(trn_str.clone());
@@ -1956,6 +2279,245 @@ fn read_input(path: Option<&PathBuf>) -> Result<String> {
     Ok(buf)
 }
 
+/// Watch `connections_path`/`tasks_path` for changes and live-reload them into `service`,
+/// debouncing bursts of filesystem events before each reload.
+async fn watch_config_files(
+    service: &OpenActService,
+    connections_path: Option<PathBuf>,
+    tasks_path: Option<PathBuf>,
+    debounce_ms: u64,
+) -> Result<()> {
+    use notify::{Event, RecursiveMode, Watcher};
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<notify::Result<Event>>();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        let _ = tx.send(res);
+    })?;
+
+    for path in connections_path.iter().chain(tasks_path.iter()) {
+        // Watch the parent directory so editors that save via rename-over-target are seen.
+        let watch_target = path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or_else(|| std::path::Path::new("."));
+        watcher.watch(watch_target, RecursiveMode::NonRecursive)?;
+    }
+
+    println!(
+        "watching for changes (debounce={}ms), press Ctrl+C to stop...",
+        debounce_ms
+    );
+    apply_config_delta(service, connections_path.as_deref(), tasks_path.as_deref()).await?;
+
+    while rx.recv().await.is_some() {
+        // Debounce: coalesce any further events that arrive within the window into one reload.
+        let deadline = tokio::time::sleep(std::time::Duration::from_millis(debounce_ms));
+        tokio::pin!(deadline);
+        loop {
+            tokio::select! {
+                _ = &mut deadline => break,
+                more = rx.recv() => {
+                    if more.is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if let Err(e) =
+            apply_config_delta(service, connections_path.as_deref(), tasks_path.as_deref()).await
+        {
+            eprintln!("reload failed: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Re-parse `connections_path`/`tasks_path` and reconcile them against what `service` currently
+/// holds, by TRN: upsert new/changed entries, delete entries no longer present, leave unchanged
+/// ones alone. Evicts the affected `client_pool` entries so stale HTTP clients built from old
+/// connection settings are not reused after a reload.
+async fn apply_config_delta(
+    service: &OpenActService,
+    connections_path: Option<&std::path::Path>,
+    tasks_path: Option<&std::path::Path>,
+) -> Result<()> {
+    let (mut added_conns, mut updated_conns, mut removed_conns) = (0usize, 0usize, 0usize);
+
+    if let Some(path) = connections_path {
+        let desired: Vec<ConnectionConfig> = parse_json_or_yaml(&std::fs::read_to_string(path)?)?;
+        let desired_by_trn: std::collections::HashMap<String, ConnectionConfig> =
+            desired.into_iter().map(|c| (c.trn.clone(), c)).collect();
+        let current = service.list_connections(None, None, None).await?;
+        let current_by_trn: std::collections::HashMap<String, ConnectionConfig> =
+            current.into_iter().map(|c| (c.trn.clone(), c)).collect();
+
+        for (trn, new_conn) in &desired_by_trn {
+            match current_by_trn.get(trn) {
+                Some(old_conn) if connection_config_eq(old_conn, new_conn) => {}
+                Some(old_conn) => {
+                    service.upsert_connection(new_conn).await?;
+                    crate::executor::client_pool::evict_for_connection(old_conn).await;
+                    updated_conns += 1;
+                }
+                None => {
+                    service.upsert_connection(new_conn).await?;
+                    added_conns += 1;
+                }
+            }
+        }
+        for (trn, old_conn) in &current_by_trn {
+            if !desired_by_trn.contains_key(trn) {
+                service.delete_connection(trn).await?;
+                crate::executor::client_pool::evict_for_connection(old_conn).await;
+                removed_conns += 1;
+            }
+        }
+    }
+
+    let (mut added_tasks, mut updated_tasks, mut removed_tasks) = (0usize, 0usize, 0usize);
+
+    if let Some(path) = tasks_path {
+        let desired: Vec<TaskConfig> = parse_json_or_yaml(&std::fs::read_to_string(path)?)?;
+        let desired_by_trn: std::collections::HashMap<String, TaskConfig> =
+            desired.into_iter().map(|t| (t.trn.clone(), t)).collect();
+        let current = service.list_tasks(None, None, None).await?;
+        let current_by_trn: std::collections::HashMap<String, TaskConfig> =
+            current.into_iter().map(|t| (t.trn.clone(), t)).collect();
+
+        for (trn, new_task) in &desired_by_trn {
+            match current_by_trn.get(trn) {
+                Some(old_task) if task_config_eq(old_task, new_task) => {}
+                Some(_) => {
+                    service.upsert_task(new_task).await?;
+                    updated_tasks += 1;
+                }
+                None => {
+                    service.upsert_task(new_task).await?;
+                    added_tasks += 1;
+                }
+            }
+        }
+        for trn in current_by_trn.keys() {
+            if !desired_by_trn.contains_key(trn) {
+                service.delete_task(trn).await?;
+                removed_tasks += 1;
+            }
+        }
+    }
+
+    println!(
+        "reloaded: +conns {added_conns} -conns {removed_conns} ~conns {updated_conns} +tasks {added_tasks} -tasks {removed_tasks} ~tasks {updated_tasks}"
+    );
+    Ok(())
+}
+
+/// Structural equality ignoring server-managed bookkeeping fields (`created_at`/`updated_at`/`version`).
+fn connection_config_eq(a: &ConnectionConfig, b: &ConnectionConfig) -> bool {
+    let normalize = |c: &ConnectionConfig| {
+        let mut c = c.clone();
+        c.created_at = Default::default();
+        c.updated_at = Default::default();
+        c.version = 0;
+        serde_json::to_value(c).ok()
+    };
+    normalize(a) == normalize(b)
+}
+
+/// Structural equality ignoring server-managed bookkeeping fields (`created_at`/`updated_at`/`version`).
+fn task_config_eq(a: &TaskConfig, b: &TaskConfig) -> bool {
+    let normalize = |t: &TaskConfig| {
+        let mut t = t.clone();
+        t.created_at = Default::default();
+        t.updated_at = Default::default();
+        t.version = 0;
+        serde_json::to_value(t).ok()
+    };
+    normalize(a) == normalize(b)
+}
+
+/// Render `system stats` data in Prometheus text exposition format so openact can be scraped
+/// directly without an adapter.
+fn render_prometheus_metrics(
+    stats: &crate::store::service::StorageStats,
+    cache: &crate::store::service::CacheStats,
+    cp: &crate::executor::client_pool::ClientPoolStats,
+) -> String {
+    let mut out = String::new();
+
+    out.push_str("# HELP openact_connections Total stored connections.\n");
+    out.push_str("# TYPE openact_connections gauge\n");
+    out.push_str(&format!("openact_connections {}\n", stats.total_connections));
+
+    out.push_str("# HELP openact_tasks Total stored tasks.\n");
+    out.push_str("# TYPE openact_tasks gauge\n");
+    out.push_str(&format!("openact_tasks {}\n", stats.total_tasks));
+
+    out.push_str("# HELP openact_auth_connections Stored auth connections by kind.\n");
+    out.push_str("# TYPE openact_auth_connections gauge\n");
+    for (kind, value) in [
+        ("api_key", stats.api_key_connections),
+        ("basic", stats.basic_connections),
+        ("oauth2_cc", stats.oauth2_cc_connections),
+        ("oauth2_ac", stats.oauth2_ac_connections),
+    ] {
+        out.push_str(&format!(
+            "openact_auth_connections{{kind=\"{}\"}} {}\n",
+            prometheus_escape_label(kind),
+            value
+        ));
+    }
+
+    out.push_str("# HELP openact_client_pool_hits_total HTTP client pool cache hits.\n");
+    out.push_str("# TYPE openact_client_pool_hits_total counter\n");
+    out.push_str(&format!("openact_client_pool_hits_total {}\n", cp.hits));
+
+    out.push_str("# HELP openact_client_pool_builds_total HTTP clients built due to a pool miss.\n");
+    out.push_str("# TYPE openact_client_pool_builds_total counter\n");
+    out.push_str(&format!("openact_client_pool_builds_total {}\n", cp.builds));
+
+    out.push_str("# HELP openact_client_pool_evictions_total HTTP clients evicted from the pool.\n");
+    out.push_str("# TYPE openact_client_pool_evictions_total counter\n");
+    out.push_str(&format!(
+        "openact_client_pool_evictions_total {}\n",
+        cp.evictions
+    ));
+
+    out.push_str("# HELP openact_client_pool_size Current number of pooled HTTP clients.\n");
+    out.push_str("# TYPE openact_client_pool_size gauge\n");
+    out.push_str(&format!("openact_client_pool_size {}\n", cp.size));
+
+    out.push_str("# HELP openact_client_pool_capacity Configured HTTP client pool capacity.\n");
+    out.push_str("# TYPE openact_client_pool_capacity gauge\n");
+    out.push_str(&format!("openact_client_pool_capacity {}\n", cp.capacity));
+
+    out.push_str("# HELP openact_cache_hit_ratio Cache hit ratio in [0,1] by cache name.\n");
+    out.push_str("# TYPE openact_cache_hit_ratio gauge\n");
+    for (name, ratio) in [
+        ("exec", cache.exec_hit_rate),
+        ("connection", cache.conn_hit_rate),
+        ("task", cache.task_hit_rate),
+    ] {
+        out.push_str(&format!(
+            "openact_cache_hit_ratio{{cache=\"{}\"}} {}\n",
+            prometheus_escape_label(name),
+            ratio
+        ));
+    }
+
+    out
+}
+
+/// Escape a Prometheus label value per the text exposition format: backslash, double-quote and
+/// newline must be backslash-escaped.
+fn prometheus_escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
 fn parse_json_or_yaml<T: serde::de::DeserializeOwned>(s: &str) -> Result<T> {
     let trimmed = s.trim_start();
     if trimmed.starts_with('{') || trimmed.starts_with('[') {
@@ -1965,12 +2527,51 @@ fn parse_json_or_yaml<T: serde::de::DeserializeOwned>(s: &str) -> Result<T> {
     }
 }
 
+/// Proxy a single request to a `--server` base URL and return the raw response body. Centralizes
+/// the request/status-check/error-surfacing boilerplate shared by every `--server` branch below;
+/// callers stay responsible for interpreting the body (JSON, plain text, ...) since each command
+/// renders it differently under `--json`.
+async fn server_request(
+    base: &str,
+    method: reqwest::Method,
+    path: &str,
+    query: &[(&str, &str)],
+    json_body: Option<&serde_json::Value>,
+) -> Result<Vec<u8>> {
+    let mut url = format!("{}{}", base.trim_end_matches('/'), path);
+    if !query.is_empty() {
+        let qs: Vec<String> = query
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
+            .collect();
+        url.push('?');
+        url.push_str(&qs.join("&"));
+    }
+    let mut req = reqwest::Client::new().request(method, &url);
+    if let Some(b) = json_body {
+        req = req.json(b);
+    }
+    let resp = req.send().await?;
+    let status = resp.status();
+    let body = resp.bytes().await?.to_vec();
+    if !status.is_success() {
+        return Err(anyhow!(
+            "server error {}: {}",
+            status,
+            String::from_utf8_lossy(&body)
+        ));
+    }
+    Ok(body)
+}
+
 /// 从CLI参数构建重试策略
 fn build_retry_policy_from_overrides(overrides: &ExecuteOverrides) -> Result<Option<RetryPolicy>> {
     // 如果没有任何重试相关参数，返回None
     if overrides.max_retries.is_none()
         && overrides.retry_delay_ms.is_none()
         && overrides.retry_policy.is_none()
+        && overrides.retry_backoff.is_none()
+        && overrides.retry_max_delay_ms.is_none()
     {
         return Ok(None);
     }
@@ -1997,6 +2598,27 @@ fn build_retry_policy_from_overrides(overrides: &ExecuteOverrides) -> Result<Opt
         policy.base_delay_ms = delay_ms;
     }
 
+    if let Some(max_delay_ms) = overrides.retry_max_delay_ms {
+        policy.max_delay_ms = max_delay_ms;
+    }
+
+    if let Some(backoff) = overrides.retry_backoff.as_deref() {
+        policy.backoff = match backoff {
+            "none" => RetryBackoff::None,
+            "exponential" => RetryBackoff::Exponential,
+            "exponential-jitter" => RetryBackoff::ExponentialJitter,
+            other => return Err(anyhow!("unknown --retry-backoff value: {other}")),
+        };
+    }
+
+    if policy.max_delay_ms < policy.base_delay_ms {
+        return Err(anyhow!(
+            "retry_max_delay_ms ({}) must be >= base retry delay ({})",
+            policy.max_delay_ms,
+            policy.base_delay_ms
+        ));
+    }
+
     Ok(Some(policy))
 }
 
@@ -2459,14 +3081,15 @@ mod cli_integration_tests {
             server: None,
             command: Commands::Oauth {
                 cmd: OauthCmd::DeviceCode {
-                    token_url: mock.url("/token"),
-                    device_code_url: mock.url("/device"),
-                    client_id: "id".to_string(),
+                    token_url: Some(mock.url("/token")),
+                    device_code_url: Some(mock.url("/device")),
+                    client_id: Some("id".to_string()),
                     client_secret: None,
                     scope: Some("repo".to_string()),
-                    tenant: "default".to_string(),
-                    provider: "github".to_string(),
-                    user_id: "alice".to_string(),
+                    tenant: Some("default".to_string()),
+                    provider: Some("github".to_string()),
+                    user_id: Some("alice".to_string()),
+                    dsl: None,
                     bind_connection: Some(conn.trn.clone()),
                 },
             },
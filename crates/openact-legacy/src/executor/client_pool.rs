@@ -1,18 +1,54 @@
 //! HTTP Client 池（按 Timeout/Network/TLS 组合复用）
+//!
+//! Sharded into `SHARD_COUNT` independent `RwLock`-guarded hash maps, keyed by hashing the pool
+//! key. This removes the single global lock the pool previously serialized every build/hit
+//! through. Recency is tracked via a per-entry atomic "last used" counter instead of an intrusive
+//! LRU list, so the common hit path only needs a read lock: the list reordering a real LRU cache
+//! requires would force a write lock on every hit, even with sharding. Eviction (on insert, once a shard is at capacity) scans that shard for the
+//! entry with the oldest counter value - an O(per-shard capacity) scan rather than the `lru`
+//! crate's O(1) intrusive pop, but shards are small (default capacity / 16) and eviction only
+//! happens on a miss, not on every hit.
 
 use anyhow::{Context, Result, anyhow};
 use reqwest::{Client, Proxy};
-use std::collections::HashMap;
-use std::sync::OnceLock;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{OnceLock, RwLock};
 use std::time::Instant;
-use tokio::sync::Mutex;
 use tracing::debug;
 
 use crate::models::{ConnectionConfig, TaskConfig};
 
-// Key -> (Client, last_access)
-static CLIENT_POOL: OnceLock<Mutex<HashMap<String, (Client, Instant)>>> = OnceLock::new();
+const SHARD_COUNT: usize = 16;
+
+/// A pooled client plus its recency, as nanoseconds elapsed since `ClientPool::start`. Recency
+/// lives in an atomic so a cache hit can bump it under a shared read lock instead of needing
+/// `&mut` access to reorder an intrusive list.
+struct CachedClient {
+    client: Client,
+    last_used_nanos: AtomicU64,
+}
+
+struct ClientPool {
+    shards: Vec<RwLock<HashMap<String, CachedClient>>>,
+    per_shard_capacity: usize,
+    start: Instant,
+    /// The set of composed `client_key` values actually built for each connection TRN, so
+    /// `evict_for_connection` can evict exactly what was inserted instead of recomputing a key
+    /// that can diverge from `client_key` whenever the connection relies on the task's
+    /// timeout/network config as a fallback (see `client_key`'s `.or(task....)`).
+    keys_by_connection: RwLock<HashMap<String, HashSet<String>>>,
+}
+
+impl ClientPool {
+    fn now_nanos(&self) -> u64 {
+        self.start.elapsed().as_nanos() as u64
+    }
+}
+
+static CLIENT_POOL: OnceLock<ClientPool> = OnceLock::new();
 
 fn pool_capacity() -> usize {
     const DEFAULT_CAP: usize = 64;
@@ -66,7 +102,7 @@ fn client_key(connection: &ConnectionConfig, task: &TaskConfig) -> String {
     key
 }
 
-// Metrics
+// Metrics (aggregated across shards)
 static HITS: AtomicU64 = AtomicU64::new(0);
 static BUILDS: AtomicU64 = AtomicU64::new(0);
 static EVICTIONS: AtomicU64 = AtomicU64::new(0);
@@ -80,11 +116,30 @@ pub struct ClientPoolStats {
     pub capacity: usize,
 }
 
+fn shard_for(key: &str) -> usize {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    (hasher.finish() as usize) % SHARD_COUNT
+}
+
+fn pool() -> &'static ClientPool {
+    CLIENT_POOL.get_or_init(|| {
+        let per_shard_capacity = (pool_capacity() / SHARD_COUNT).max(1);
+        ClientPool {
+            shards: (0..SHARD_COUNT).map(|_| RwLock::new(HashMap::new())).collect(),
+            per_shard_capacity,
+            start: Instant::now(),
+            keys_by_connection: RwLock::new(HashMap::new()),
+        }
+    })
+}
+
 pub fn get_stats() -> ClientPoolStats {
-    let size = CLIENT_POOL
-        .get()
-        .and_then(|m| m.try_lock().ok().map(|g| g.len()))
-        .unwrap_or(0);
+    let size: usize = pool()
+        .shards
+        .iter()
+        .filter_map(|s| s.try_read().ok().map(|g| g.len()))
+        .sum();
     ClientPoolStats {
         hits: HITS.load(Ordering::Relaxed),
         builds: BUILDS.load(Ordering::Relaxed),
@@ -94,15 +149,55 @@ pub fn get_stats() -> ClientPoolStats {
     }
 }
 
+/// Evict every pooled client that was ever built for this connection, however the key that
+/// built it was composed. Used by config hot-reload so a changed or removed connection doesn't
+/// keep reusing a client built from its old settings.
+///
+/// This evicts via `keys_by_connection`, the set of keys actually inserted by `get_client_for`
+/// for this connection's TRN, rather than recomputing a key from the connection's own fields: a
+/// connection that only sets one of timeout/network and relies on the task for the other
+/// composes its `client_key` from both, so recomputing from the connection alone would miss it.
+pub async fn evict_for_connection(connection: &ConnectionConfig) {
+    let keys = match pool().keys_by_connection.write() {
+        Ok(mut index) => index.remove(&connection.trn).unwrap_or_default(),
+        Err(_) => return,
+    };
+    for key in keys {
+        let shard = &pool().shards[shard_for(&key)];
+        if let Ok(mut guard) = shard.write() {
+            if guard.remove(&key).is_some() {
+                EVICTIONS.fetch_add(1, Ordering::Relaxed);
+                debug!(target: "client_pool", evict=true, reason="config_reload", key=%key, "evict client after connection config change");
+            }
+        }
+    }
+}
+
+/// Records that `key` was composed for `connection_trn`, so `evict_for_connection` can find it
+/// later regardless of whether the key came from the connection's own timeout/network config or
+/// fell back to the task's.
+fn record_key_for_connection(connection_trn: &str, key: &str) {
+    if let Ok(mut index) = pool().keys_by_connection.write() {
+        index
+            .entry(connection_trn.to_string())
+            .or_default()
+            .insert(key.to_string());
+    }
+}
+
 pub fn get_client_for(connection: &ConnectionConfig, task: &TaskConfig) -> Result<Client> {
-    let pool = CLIENT_POOL.get_or_init(|| Mutex::new(HashMap::new()));
     let key = client_key(connection, task);
-    // fast path: try lock and get
-    if let Ok(mut guard) = pool.try_lock() {
-        if let Some((c, ts)) = guard.get_mut(&key) {
-            // update last access to improve LRU accuracy
-            *ts = Instant::now();
-            let client = c.clone();
+    record_key_for_connection(&connection.trn, &key);
+    let pool = pool();
+    let shard = &pool.shards[shard_for(&key)];
+
+    // Hit path: a shared read lock. Bumping recency only touches this entry's own atomic
+    // counter, so it needs no exclusive access to the shard - unlike an intrusive LRU list,
+    // which must reorder under `&mut self` on every touch.
+    if let Ok(guard) = shard.read() {
+        if let Some(entry) = guard.get(&key) {
+            entry.last_used_nanos.store(pool.now_nanos(), Ordering::Relaxed);
+            let client = entry.client.clone();
             let size = guard.len();
             drop(guard);
             HITS.fetch_add(1, Ordering::Relaxed);
@@ -160,38 +255,42 @@ pub fn get_client_for(connection: &ConnectionConfig, task: &TaskConfig) -> Resul
     let client = builder.build().context("Failed to create HTTP client")?;
     BUILDS.fetch_add(1, Ordering::Relaxed);
     debug!(target: "client_pool", build=true, "build new http client");
-    // store in pool with LRU eviction (best-effort, avoid blocking in async context)
-    if let Ok(mut guard) = pool.try_lock() {
-        // cleanup stale entries by TTL
-        let ttl = std::time::Duration::from_secs(pool_ttl_secs());
-        let now = Instant::now();
-        let mut stale: Vec<String> = Vec::new();
-        for (k, (_c, ts)) in guard.iter() {
-            if now.duration_since(*ts) > ttl {
-                stale.push(k.clone());
-            }
-        }
+
+    // store in pool; TTL sweep and capacity eviction are scoped to this shard only (best-effort,
+    // avoid blocking in a sync context).
+    if let Ok(mut guard) = shard.try_write() {
+        let ttl_nanos = pool_ttl_secs().saturating_mul(1_000_000_000);
+        let now = pool.now_nanos();
+        let stale: Vec<String> = guard
+            .iter()
+            .filter(|(_k, entry)| {
+                now.saturating_sub(entry.last_used_nanos.load(Ordering::Relaxed)) > ttl_nanos
+            })
+            .map(|(k, _)| k.clone())
+            .collect();
         for k in stale {
-            let _ = guard.remove(&k);
+            guard.remove(&k);
         }
 
-        // insert current
-        guard.insert(key.clone(), (client.clone(), Instant::now()));
-        let cap = pool_capacity();
-        if guard.len() > cap {
-            // evict least-recently used (oldest last_access)
+        // Capacity eviction: scan for the least-recently-used entry rather than popping an
+        // intrusive list head. O(per-shard capacity), not O(1), but shards stay small and this
+        // only runs on a miss.
+        if guard.len() >= pool.per_shard_capacity && !guard.contains_key(&key) {
             if let Some(evict_key) = guard
                 .iter()
-                .min_by_key(|(_k, (_c, ts))| *ts)
+                .min_by_key(|(_, entry)| entry.last_used_nanos.load(Ordering::Relaxed))
                 .map(|(k, _)| k.clone())
             {
-                if evict_key != key {
-                    let _ = guard.remove(&evict_key);
-                    EVICTIONS.fetch_add(1, Ordering::Relaxed);
-                    debug!(target: "client_pool", evict=true, size=%guard.len(), capacity=%cap, key=%evict_key, "evict least-recently used client");
-                }
+                guard.remove(&evict_key);
+                EVICTIONS.fetch_add(1, Ordering::Relaxed);
+                debug!(target: "client_pool", evict=true, size=%guard.len(), key=%evict_key, "evict least-recently used client");
             }
         }
+
+        guard.insert(
+            key.clone(),
+            CachedClient { client: client.clone(), last_used_nanos: AtomicU64::new(now) },
+        );
     }
     Ok(client)
 }
@@ -785,6 +785,7 @@ mod tests {
             backoff_multiplier: 2.0,
             retry_status_codes: vec![429, 500, 502, 503, 504],
             respect_retry_after: true,
+            backoff: crate::models::common::RetryBackoff::None,
         };
 
         // Test delay without Retry-After
@@ -817,6 +818,7 @@ mod tests {
             backoff_multiplier: 2.0,
             retry_status_codes: vec![429, 500, 502, 503, 504],
             respect_retry_after: false,
+            backoff: crate::models::common::RetryBackoff::None,
         };
         let delay_ignored = executor
             .calculate_delay(&retry_policy_no_respect, 1, Some(Duration::from_millis(50)))
@@ -136,6 +136,25 @@ impl Default for ResponsePolicy {
     }
 }
 
+/// How the delay between retry attempts grows as attempts accumulate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum RetryBackoff {
+    /// `base_delay * backoff_multiplier^(attempt - 1)`, capped at `max_delay_ms` (legacy behavior)
+    None,
+    /// `base_delay * 2^(attempt - 1)`, capped at `max_delay_ms`
+    Exponential,
+    /// AWS-style "full jitter": uniformly random in `[0, min(max_delay_ms, base_delay * 2^(attempt - 1))]`
+    ExponentialJitter,
+}
+
+impl Default for RetryBackoff {
+    fn default() -> Self {
+        RetryBackoff::None
+    }
+}
+
 /// Retry policy configuration for HTTP requests
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -152,6 +171,9 @@ pub struct RetryPolicy {
     pub retry_status_codes: Vec<u16>,
     /// Whether to respect Retry-After headers
     pub respect_retry_after: bool,
+    /// How the delay grows between attempts
+    #[serde(default)]
+    pub backoff: RetryBackoff,
 }
 
 impl Default for RetryPolicy {
@@ -163,6 +185,7 @@ impl Default for RetryPolicy {
             backoff_multiplier: 2.0,
             retry_status_codes: vec![429, 500, 502, 503, 504], // Common retry-able status codes
             respect_retry_after: true,
+            backoff: RetryBackoff::None,
         }
     }
 }
@@ -174,10 +197,37 @@ impl RetryPolicy {
             return std::time::Duration::ZERO;
         }
 
-        let delay_ms =
-            (self.base_delay_ms as f64 * self.backoff_multiplier.powi(attempt as i32 - 1)) as u64;
-        let delay_ms = delay_ms.min(self.max_delay_ms);
-        std::time::Duration::from_millis(delay_ms)
+        match self.backoff {
+            RetryBackoff::None => {
+                let delay_ms = (self.base_delay_ms as f64
+                    * self.backoff_multiplier.powi(attempt as i32 - 1))
+                    as u64;
+                let delay_ms = delay_ms.min(self.max_delay_ms);
+                std::time::Duration::from_millis(delay_ms)
+            }
+            RetryBackoff::Exponential => {
+                std::time::Duration::from_millis(self.exponential_cap_ms(attempt))
+            }
+            RetryBackoff::ExponentialJitter => {
+                let cap = self.exponential_cap_ms(attempt);
+                let jittered = if cap == 0 {
+                    0
+                } else {
+                    rand::Rng::gen_range(&mut rand::thread_rng(), 0..=cap)
+                };
+                std::time::Duration::from_millis(jittered)
+            }
+        }
+    }
+
+    /// `min(max_delay_ms, base_delay_ms * 2^(attempt - 1))`, matching the 1-based attempt
+    /// numbering used by the `RetryBackoff::None` branch above (first retry = 1 => base delay).
+    /// The exponent is clamped to avoid overflow on pathologically large attempt counts.
+    fn exponential_cap_ms(&self, attempt: u32) -> u64 {
+        let shift = attempt.saturating_sub(1).min(63);
+        let multiplier = 1u64.checked_shl(shift).unwrap_or(u64::MAX);
+        let scaled = self.base_delay_ms.saturating_mul(multiplier);
+        scaled.min(self.max_delay_ms)
     }
 
     /// Check if a status code should trigger a retry
@@ -194,6 +244,7 @@ impl RetryPolicy {
             backoff_multiplier: 1.5,
             retry_status_codes: vec![408, 429, 500, 502, 503, 504],
             respect_retry_after: true,
+            backoff: RetryBackoff::None,
         }
     }
 
@@ -206,6 +257,47 @@ impl RetryPolicy {
             backoff_multiplier: 2.0,
             retry_status_codes: vec![429, 503, 504],
             respect_retry_after: true,
+            backoff: RetryBackoff::None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy_with_backoff(backoff: RetryBackoff) -> RetryPolicy {
+        RetryPolicy { backoff, ..RetryPolicy::default() }
+    }
+
+    #[test]
+    fn exponential_first_retry_equals_base_delay() {
+        let policy = policy_with_backoff(RetryBackoff::Exponential);
+        assert_eq!(
+            policy.delay_for_attempt(1),
+            std::time::Duration::from_millis(policy.base_delay_ms)
+        );
+    }
+
+    #[test]
+    fn exponential_jitter_first_retry_never_exceeds_base_delay() {
+        let policy = policy_with_backoff(RetryBackoff::ExponentialJitter);
+        for _ in 0..20 {
+            assert!(policy.delay_for_attempt(1) <= std::time::Duration::from_millis(policy.base_delay_ms));
+        }
+    }
+
+    #[test]
+    fn exponential_doubles_per_attempt_and_caps() {
+        let policy = policy_with_backoff(RetryBackoff::Exponential);
+        assert_eq!(
+            policy.delay_for_attempt(2),
+            std::time::Duration::from_millis(policy.base_delay_ms * 2)
+        );
+        assert_eq!(
+            policy.delay_for_attempt(3),
+            std::time::Duration::from_millis(policy.base_delay_ms * 4)
+        );
+        assert_eq!(policy.delay_for_attempt(100), std::time::Duration::from_millis(policy.max_delay_ms));
+    }
+}
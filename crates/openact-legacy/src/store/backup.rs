@@ -0,0 +1,135 @@
+//! Encrypted, portable backup/restore for connections and tasks.
+//!
+//! `ConfigCmd::Export` dumps connections/tasks as plaintext JSON/YAML, which leaks API keys,
+//! OAuth client secrets and stored tokens. A backup instead seals the same payload with a key
+//! derived from an operator-supplied passphrase (Argon2id + XChaCha20-Poly1305), so the
+//! resulting archive is safe to store off-box.
+
+use anyhow::{Context, Result, anyhow};
+use argon2::Argon2;
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use chacha20poly1305::{
+    XChaCha20Poly1305, XNonce,
+    aead::{Aead, KeyInit},
+};
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{ConnectionConfig, TaskConfig};
+
+const BACKUP_VERSION: u32 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+/// A versioned, self-describing backup archive. The connection/task payload is stored as the
+/// `ciphertext` field; everything else is the header needed to decrypt and sanity-check it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupArchive {
+    pub openact_backup_version: u32,
+    pub created_at: DateTime<Utc>,
+    pub connections_count: usize,
+    pub tasks_count: usize,
+    /// Base64-encoded Argon2id salt
+    pub salt: String,
+    /// Base64-encoded XChaCha20-Poly1305 nonce
+    pub nonce: String,
+    /// Base64-encoded ciphertext (AEAD tag included)
+    pub ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BackupPayload {
+    connections: Vec<ConnectionConfig>,
+    tasks: Vec<TaskConfig>,
+}
+
+/// Encrypt `connections`/`tasks` into a single passphrase-protected archive.
+pub fn encrypt_backup(
+    connections: Vec<ConnectionConfig>,
+    tasks: Vec<TaskConfig>,
+    passphrase: &str,
+) -> Result<BackupArchive> {
+    let connections_count = connections.len();
+    let tasks_count = tasks.len();
+
+    let mut salt = [0u8; SALT_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce_bytes);
+
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = XChaCha20Poly1305::new((&key).into());
+    let nonce = XNonce::from_slice(&nonce_bytes);
+
+    let plaintext = serde_json::to_vec(&BackupPayload { connections, tasks })
+        .context("failed to serialize backup payload")?;
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext.as_ref())
+        .map_err(|_| anyhow!("backup encryption failed"))?;
+
+    Ok(BackupArchive {
+        openact_backup_version: BACKUP_VERSION,
+        created_at: Utc::now(),
+        connections_count,
+        tasks_count,
+        salt: STANDARD.encode(salt),
+        nonce: STANDARD.encode(nonce_bytes),
+        ciphertext: STANDARD.encode(ciphertext),
+    })
+}
+
+/// Verify the archive version and AEAD tag, then decrypt back into connections/tasks. Refuses
+/// to return anything on a version mismatch, a wrong passphrase, or a corrupted archive.
+pub fn decrypt_backup(
+    archive: &BackupArchive,
+    passphrase: &str,
+) -> Result<(Vec<ConnectionConfig>, Vec<TaskConfig>)> {
+    if archive.openact_backup_version != BACKUP_VERSION {
+        return Err(anyhow!(
+            "unsupported backup version: {} (expected {})",
+            archive.openact_backup_version,
+            BACKUP_VERSION
+        ));
+    }
+
+    let salt = STANDARD
+        .decode(&archive.salt)
+        .context("invalid backup salt encoding")?;
+    let nonce_bytes = STANDARD
+        .decode(&archive.nonce)
+        .context("invalid backup nonce encoding")?;
+    let ciphertext = STANDARD
+        .decode(&archive.ciphertext)
+        .context("invalid backup ciphertext encoding")?;
+    if nonce_bytes.len() != NONCE_LEN {
+        return Err(anyhow!("invalid backup nonce length"));
+    }
+
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = XChaCha20Poly1305::new((&key).into());
+    let nonce = XNonce::from_slice(&nonce_bytes);
+
+    let plaintext = cipher
+        .decrypt(nonce, ciphertext.as_ref())
+        .map_err(|_| anyhow!("failed to decrypt backup: wrong passphrase or corrupted archive"))?;
+
+    let payload: BackupPayload =
+        serde_json::from_slice(&plaintext).context("corrupted backup payload")?;
+
+    if payload.connections.len() != archive.connections_count
+        || payload.tasks.len() != archive.tasks_count
+    {
+        return Err(anyhow!("backup header counts do not match decrypted payload"));
+    }
+
+    Ok((payload.connections, payload.tasks))
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32]> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("key derivation failed: {}", e))?;
+    Ok(key)
+}
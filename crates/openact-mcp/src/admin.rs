@@ -0,0 +1,422 @@
+//! Governed admin tools for connection lifecycle management.
+//!
+//! These wrap `AuthConnectionStore` operations (list/get/delete/cleanup, plus the RFC 8628
+//! device-authorization grant) as MCP tools. They sit outside the normal per-action tool surface
+//! derived from `ActionRepository`, so they're gated separately behind
+//! `GovernanceConfig::admin_enabled` (off by default) rather than just the allow/deny pattern
+//! list, since a wildcard allow pattern shouldn't silently grant access to connection secrets.
+
+use chrono::{DateTime, Utc};
+use openact_core::store::AuthConnectionStore;
+use openact_core::types::AuthConnection;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::mcp::{Tool, ToolAnnotations, ToolInputSchema};
+use crate::{McpError, McpResult};
+
+pub const TOOL_CONNECTION_LIST: &str = "admin.connection.list";
+pub const TOOL_CONNECTION_GET: &str = "admin.connection.get";
+pub const TOOL_CONNECTION_DELETE: &str = "admin.connection.delete";
+pub const TOOL_CONNECTION_CLEANUP_EXPIRED: &str = "admin.connection.cleanup_expired";
+pub const TOOL_DEVICE_AUTHORIZE: &str = "admin.oauth2.device_authorize";
+pub const TOOL_DEVICE_POLL: &str = "admin.oauth2.device_poll";
+
+const ADMIN_TOOL_NAMES: &[&str] = &[
+    TOOL_CONNECTION_LIST,
+    TOOL_CONNECTION_GET,
+    TOOL_CONNECTION_DELETE,
+    TOOL_CONNECTION_CLEANUP_EXPIRED,
+    TOOL_DEVICE_AUTHORIZE,
+    TOOL_DEVICE_POLL,
+];
+
+/// True for any tool name handled by `dispatch_admin_tool`.
+pub fn is_admin_tool(name: &str) -> bool {
+    ADMIN_TOOL_NAMES.contains(&name)
+}
+
+/// Tool definitions for `tools/list`, in the same shape `server.rs` uses for `openact.execute`.
+pub fn admin_tool_defs() -> Vec<Tool> {
+    vec![
+        Tool {
+            name: TOOL_CONNECTION_LIST.to_string(),
+            description: Some("List stored auth connection references".to_string()),
+            title: Some("List Auth Connections".to_string()),
+            annotations: Some(ToolAnnotations {
+                destructive_hint: Some(false),
+                idempotent_hint: Some(true),
+                open_world_hint: None,
+                read_only_hint: Some(true),
+                title: Some("List auth connections".to_string()),
+            }),
+            input_schema: ToolInputSchema {
+                r#type: "object".into(),
+                properties: Some(json!({})),
+                required: None,
+            },
+            output_schema: None,
+        },
+        Tool {
+            name: TOOL_CONNECTION_GET.to_string(),
+            description: Some(
+                "Get an auth connection by reference, with token values redacted".to_string(),
+            ),
+            title: Some("Get Auth Connection".to_string()),
+            annotations: Some(ToolAnnotations {
+                destructive_hint: Some(false),
+                idempotent_hint: Some(true),
+                open_world_hint: None,
+                read_only_hint: Some(true),
+                title: Some("Get auth connection".to_string()),
+            }),
+            input_schema: ToolInputSchema {
+                r#type: "object".into(),
+                properties: Some(json!({
+                    "auth_ref": {"type": "string", "description": "Auth connection reference (TRN)"}
+                })),
+                required: Some(vec!["auth_ref".into()]),
+            },
+            output_schema: None,
+        },
+        Tool {
+            name: TOOL_CONNECTION_DELETE.to_string(),
+            description: Some("Delete an auth connection by reference".to_string()),
+            title: Some("Delete Auth Connection".to_string()),
+            annotations: Some(ToolAnnotations {
+                destructive_hint: Some(true),
+                idempotent_hint: Some(true),
+                open_world_hint: None,
+                read_only_hint: Some(false),
+                title: Some("Delete auth connection".to_string()),
+            }),
+            input_schema: ToolInputSchema {
+                r#type: "object".into(),
+                properties: Some(json!({
+                    "auth_ref": {"type": "string", "description": "Auth connection reference (TRN)"}
+                })),
+                required: Some(vec!["auth_ref".into()]),
+            },
+            output_schema: None,
+        },
+        Tool {
+            name: TOOL_CONNECTION_CLEANUP_EXPIRED.to_string(),
+            description: Some(
+                "Delete all expired auth connections, returning the number removed".to_string(),
+            ),
+            title: Some("Cleanup Expired Auth Connections".to_string()),
+            annotations: Some(ToolAnnotations {
+                destructive_hint: Some(true),
+                idempotent_hint: Some(true),
+                open_world_hint: None,
+                read_only_hint: Some(false),
+                title: Some("Cleanup expired auth connections".to_string()),
+            }),
+            input_schema: ToolInputSchema {
+                r#type: "object".into(),
+                properties: Some(json!({})),
+                required: None,
+            },
+            output_schema: None,
+        },
+        Tool {
+            name: TOOL_DEVICE_AUTHORIZE.to_string(),
+            description: Some(
+                "Start an RFC 8628 device-authorization grant, returning a user_code and \
+                 verification_uri to present to the user"
+                    .to_string(),
+            ),
+            title: Some("Begin Device Authorization".to_string()),
+            annotations: Some(ToolAnnotations {
+                destructive_hint: Some(false),
+                idempotent_hint: Some(false),
+                open_world_hint: Some(true),
+                read_only_hint: Some(false),
+                title: Some("Begin device authorization".to_string()),
+            }),
+            input_schema: ToolInputSchema {
+                r#type: "object".into(),
+                properties: Some(json!({
+                    "device_authorization_endpoint": {"type": "string", "description": "Provider's device authorization endpoint"},
+                    "client_id": {"type": "string", "description": "OAuth2 client id"},
+                    "scope": {"type": "string", "description": "Requested scope(s)"}
+                })),
+                required: Some(vec![
+                    "device_authorization_endpoint".into(),
+                    "client_id".into(),
+                ]),
+            },
+            output_schema: None,
+        },
+        Tool {
+            name: TOOL_DEVICE_POLL.to_string(),
+            description: Some(
+                "Poll the token endpoint once for a pending device-authorization grant; on \
+                 success, stores the resulting auth connection"
+                    .to_string(),
+            ),
+            title: Some("Poll Device Authorization".to_string()),
+            annotations: Some(ToolAnnotations {
+                destructive_hint: Some(false),
+                idempotent_hint: Some(false),
+                open_world_hint: Some(true),
+                read_only_hint: Some(false),
+                title: Some("Poll device authorization".to_string()),
+            }),
+            input_schema: ToolInputSchema {
+                r#type: "object".into(),
+                properties: Some(json!({
+                    "token_endpoint": {"type": "string", "description": "Provider's token endpoint"},
+                    "client_id": {"type": "string", "description": "OAuth2 client id"},
+                    "client_secret": {"type": "string", "description": "OAuth2 client secret (confidential clients only)"},
+                    "session": {"type": "object", "description": "The session object returned by admin.oauth2.device_authorize"},
+                    "tenant": {"type": "string", "description": "Tenant the resulting connection belongs to"},
+                    "provider": {"type": "string", "description": "Provider name to store the connection under"},
+                    "user_id": {"type": "string", "description": "User id to store the connection under"}
+                })),
+                required: Some(vec![
+                    "token_endpoint".into(),
+                    "client_id".into(),
+                    "session".into(),
+                    "tenant".into(),
+                    "provider".into(),
+                    "user_id".into(),
+                ]),
+            },
+            output_schema: None,
+        },
+    ]
+}
+
+/// Session handed back by `admin.oauth2.device_authorize` and round-tripped into
+/// `admin.oauth2.device_poll`. Mirrors `authflow::actions::oauth2::device::DeviceAuthSession`,
+/// duplicated here since this crate has no dependency on the top-level `authflow` crate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DeviceAuthSession {
+    device_code: String,
+    user_code: String,
+    verification_uri: String,
+    #[serde(default)]
+    verification_uri_complete: Option<String>,
+    interval_secs: u64,
+    expires_at: DateTime<Utc>,
+}
+
+/// RFC 8628 section 3.1/3.2: request a device and user code from the authorization server.
+async fn begin_device_auth(
+    device_authorization_endpoint: &str,
+    client_id: &str,
+    scope: Option<&str>,
+) -> McpResult<DeviceAuthSession> {
+    let mut form = vec![("client_id", client_id)];
+    if let Some(s) = scope {
+        form.push(("scope", s));
+    }
+
+    let resp = reqwest::Client::new()
+        .post(device_authorization_endpoint)
+        .form(&form)
+        .send()
+        .await
+        .map_err(|e| McpError::Internal(format!("device authorization request failed: {}", e)))?;
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(McpError::Internal(format!(
+            "device authorization request failed: {} - {}",
+            status, body
+        )));
+    }
+
+    let body: Value = resp
+        .json()
+        .await
+        .map_err(|e| McpError::Internal(format!("invalid device authorization response: {}", e)))?;
+    let device_code = body
+        .get("device_code")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::Internal("missing device_code".to_string()))?
+        .to_string();
+    let user_code = body
+        .get("user_code")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::Internal("missing user_code".to_string()))?
+        .to_string();
+    let verification_uri = body
+        .get("verification_uri")
+        .or_else(|| body.get("verification_url"))
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::Internal("missing verification_uri".to_string()))?
+        .to_string();
+    let verification_uri_complete = body
+        .get("verification_uri_complete")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+    let expires_in = body.get("expires_in").and_then(|v| v.as_i64()).unwrap_or(1800);
+    let interval_secs = body.get("interval").and_then(|v| v.as_u64()).unwrap_or(5);
+
+    Ok(DeviceAuthSession {
+        device_code,
+        user_code,
+        verification_uri,
+        verification_uri_complete,
+        interval_secs,
+        expires_at: Utc::now() + chrono::Duration::seconds(expires_in),
+    })
+}
+
+/// RFC 8628 section 3.4/3.5: poll the token endpoint once. Returns the parsed token response on
+/// success; the caller is responsible for waiting `interval_secs` between `pending`/`slow_down`
+/// outcomes.
+async fn poll_device_auth(
+    token_endpoint: &str,
+    client_id: &str,
+    client_secret: Option<&str>,
+    session: &DeviceAuthSession,
+) -> McpResult<Value> {
+    if Utc::now() > session.expires_at {
+        return Err(McpError::Internal(
+            "device code expired before authorization completed".to_string(),
+        ));
+    }
+
+    let mut form = vec![
+        ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+        ("device_code", session.device_code.as_str()),
+        ("client_id", client_id),
+    ];
+    if let Some(cs) = client_secret {
+        form.push(("client_secret", cs));
+    }
+
+    let resp = reqwest::Client::new()
+        .post(token_endpoint)
+        .form(&form)
+        .send()
+        .await
+        .map_err(|e| McpError::Internal(format!("token polling failed: {}", e)))?;
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        if body.contains("authorization_pending") {
+            return Ok(json!({ "status": "authorization_pending", "interval_secs": session.interval_secs }));
+        }
+        if body.contains("slow_down") {
+            // RFC 8628 section 3.5: back off by 5 seconds on every slow_down response.
+            return Ok(json!({ "status": "slow_down", "interval_secs": session.interval_secs + 5 }));
+        }
+        return Err(McpError::Internal(format!("token polling failed: {} - {}", status, body)));
+    }
+
+    resp.json()
+        .await
+        .map_err(|e| McpError::Internal(format!("invalid token response: {}", e)))
+}
+
+/// Drops token material, keeping only what's useful for identifying/auditing a connection.
+fn redact(connection: &AuthConnection) -> Value {
+    json!({
+        "trn": connection.trn,
+        "tenant": connection.tenant,
+        "provider": connection.provider,
+        "user_id": connection.user_id,
+        "token_type": connection.token_type,
+        "scope": connection.scope,
+        "has_access_token": !connection.access_token.is_empty(),
+        "has_refresh_token": connection.refresh_token.is_some(),
+        "expires_at": connection.expires_at,
+        "created_at": connection.created_at,
+        "updated_at": connection.updated_at,
+        "version": connection.version,
+    })
+}
+
+fn required_str<'a>(arguments: &'a Value, field: &str) -> McpResult<&'a str> {
+    arguments
+        .get(field)
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| McpError::InvalidArguments(format!("Missing or empty '{}' field", field)))
+}
+
+/// Executes one of the `admin.connection.*`/`admin.oauth2.device_*` tools against `store`.
+/// Callers are responsible for checking `GovernanceConfig::admin_enabled` (and allow/deny
+/// patterns) before reaching here.
+pub async fn dispatch_admin_tool(
+    store: &dyn AuthConnectionStore,
+    name: &str,
+    arguments: &Value,
+) -> McpResult<Value> {
+    match name {
+        TOOL_CONNECTION_LIST => {
+            let refs = store.list_refs().await?;
+            Ok(json!({ "count": refs.len(), "auth_refs": refs }))
+        }
+        TOOL_CONNECTION_GET => {
+            let auth_ref = required_str(arguments, "auth_ref")?;
+            let connection = store.get(auth_ref).await?;
+            Ok(json!({ "connection": connection.as_ref().map(redact) }))
+        }
+        TOOL_CONNECTION_DELETE => {
+            let auth_ref = required_str(arguments, "auth_ref")?;
+            let deleted = store.delete(auth_ref).await?;
+            Ok(json!({ "deleted": deleted }))
+        }
+        TOOL_CONNECTION_CLEANUP_EXPIRED => {
+            let removed = store.cleanup_expired().await?;
+            Ok(json!({ "removed": removed }))
+        }
+        TOOL_DEVICE_AUTHORIZE => {
+            let endpoint = required_str(arguments, "device_authorization_endpoint")?;
+            let client_id = required_str(arguments, "client_id")?;
+            let scope = arguments.get("scope").and_then(|v| v.as_str());
+            let session = begin_device_auth(endpoint, client_id, scope).await?;
+            Ok(serde_json::to_value(session)?)
+        }
+        TOOL_DEVICE_POLL => {
+            let token_endpoint = required_str(arguments, "token_endpoint")?;
+            let client_id = required_str(arguments, "client_id")?;
+            let client_secret = arguments.get("client_secret").and_then(|v| v.as_str());
+            let session: DeviceAuthSession = serde_json::from_value(
+                arguments
+                    .get("session")
+                    .cloned()
+                    .ok_or_else(|| McpError::InvalidArguments("Missing 'session' field".to_string()))?,
+            )
+            .map_err(|e| McpError::InvalidArguments(format!("invalid 'session' field: {}", e)))?;
+            let tenant = required_str(arguments, "tenant")?;
+            let provider = required_str(arguments, "provider")?;
+            let user_id = required_str(arguments, "user_id")?;
+
+            let token_json = poll_device_auth(token_endpoint, client_id, client_secret, &session).await?;
+            if token_json.get("status").is_some() {
+                // Still pending/slow_down: pass the RFC 8628 status straight through.
+                return Ok(token_json);
+            }
+
+            let access_token = token_json
+                .get("access_token")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| McpError::Internal("missing access_token".to_string()))?;
+            let mut connection = AuthConnection::new(tenant, provider, user_id, access_token);
+            if let Some(rt) = token_json.get("refresh_token").and_then(|v| v.as_str()) {
+                connection.refresh_token = Some(rt.to_string());
+            }
+            if let Some(tt) = token_json.get("token_type").and_then(|v| v.as_str()) {
+                connection.token_type = tt.to_string();
+            }
+            if let Some(exp) = token_json.get("expires_in").and_then(|v| v.as_i64()) {
+                connection.expires_at = Some(Utc::now() + chrono::Duration::seconds(exp));
+            }
+            if let Some(sc) = token_json.get("scope").and_then(|v| v.as_str()) {
+                connection.scope = Some(sc.to_string());
+            }
+            connection.extra = token_json;
+
+            let auth_ref = connection.trn.clone();
+            store.put(&auth_ref, &connection).await?;
+            Ok(json!({ "status": "complete", "auth_ref": auth_ref }))
+        }
+        other => Err(McpError::ToolNotFound(format!("Unknown admin tool: {}", other))),
+    }
+}
@@ -17,6 +17,10 @@ pub struct GovernanceConfig {
     pub timeout: Duration,
     /// Semaphore for concurrency control
     pub concurrency_limiter: Arc<Semaphore>,
+    /// Whether the `admin.*` connection-lifecycle tools are exposed. Off by default: these tools
+    /// sit outside the per-action tool surface and can read or delete auth connections directly.
+    /// When enabled, the allow/deny patterns still apply to them.
+    pub admin_enabled: bool,
 }
 
 impl GovernanceConfig {
@@ -33,9 +37,16 @@ impl GovernanceConfig {
             max_concurrency,
             timeout: Duration::from_secs(timeout_secs),
             concurrency_limiter: Arc::new(Semaphore::new(max_concurrency)),
+            admin_enabled: false,
         }
     }
 
+    /// Opt into the `admin.*` connection-lifecycle tools (off by default).
+    pub fn with_admin_enabled(mut self, enabled: bool) -> Self {
+        self.admin_enabled = enabled;
+        self
+    }
+
     /// Check if a tool is allowed by governance policies
     pub fn is_tool_allowed(&self, tool_name: &str) -> bool {
         // If allow patterns are specified, tool must match at least one
@@ -164,4 +175,13 @@ mod tests {
         // Not allowed
         assert!(!config.is_tool_allowed("postgres.query"));
     }
+
+    #[test]
+    fn test_admin_enabled_default_off() {
+        let config = GovernanceConfig::default();
+        assert!(!config.admin_enabled);
+
+        let config = config.with_admin_enabled(true);
+        assert!(config.admin_enabled);
+    }
 }
@@ -4,6 +4,7 @@
 //! to be exposed as MCP tools. Implementation follows the same pattern as the Go reference.
 
 pub mod adapter;
+pub mod admin;
 pub mod app_state;
 pub mod error;
 pub mod governance;
@@ -190,6 +190,17 @@ impl McpServer {
             debug!("Tool '{}' filtered by governance policy", openact_execute_name);
         }
 
+        // Admin connection-lifecycle tools, only surfaced when explicitly opted into.
+        if self.governance.admin_enabled {
+            for tool in crate::admin::admin_tool_defs() {
+                if self.governance.is_tool_allowed(&tool.name) {
+                    tools.push(tool);
+                } else {
+                    debug!("Tool '{}' filtered by governance policy", tool.name);
+                }
+            }
+        }
+
         // Optimize: Get all MCP-enabled actions in one query to avoid N+1
         let all_actions = self.get_all_mcp_enabled_actions(tenant_ctx.as_deref()).await?;
         let mut tool_names_seen = HashSet::new();
@@ -321,6 +332,34 @@ impl McpServer {
                     let result = self.execute_openact_action(args_ref).await?;
                     Ok(success_response(request.id.clone(), serde_json::to_value(result)?))
                 }
+                name if crate::admin::is_admin_tool(name) => {
+                    if !self.governance.admin_enabled {
+                        return Err(McpError::PermissionDenied(format!(
+                            "Admin tools are disabled: {}",
+                            name
+                        )));
+                    }
+                    let empty = serde_json::json!({});
+                    let args_ref = call_request.arguments.as_ref().unwrap_or(&empty);
+                    let result = crate::admin::dispatch_admin_tool(
+                        self.app_state.store.as_ref(),
+                        name,
+                        args_ref,
+                    )
+                    .await?;
+                    let text = serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string());
+                    let block = ContentBlock::TextContent(openact_mcp_types::TextContent {
+                        annotations: None,
+                        text,
+                        r#type: "text".into(),
+                    });
+                    let response = ToolsCallResponse {
+                        content: vec![block],
+                        is_error: None,
+                        structured_content: Some(result),
+                    };
+                    Ok(success_response(request.id.clone(), serde_json::to_value(response)?))
+                }
                 // For per-action tools (both direct connector.action and aliased tools)
                 other => {
                     let (connector, action) = self.resolve_tool_to_action(other).await?;
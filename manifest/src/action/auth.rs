@@ -2,11 +2,17 @@
 // Provides authentication context injection for API calls
 
 use crate::utils::error::{OpenApiToolError, Result};
+use authflow::store::connection_store::{refresh_if_expiring, Connection};
 use authflow::store::{create_connection_store, ConnectionStore, StoreBackend, StoreConfig};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
+
+/// How close to expiry (or already expired) a stored token must be before
+/// [`AuthAdapter::get_auth_context_by_trn`] refreshes it via [`refresh_if_expiring`].
+const TOKEN_REFRESH_SKEW: Duration = Duration::from_secs(60);
 
 /// Authentication configuration parsed from x-auth extension (spec compliant)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -242,21 +248,30 @@ impl AuthAdapter {
     /// Get authentication context by TRN (stub implementation)
     pub async fn get_auth_context_by_trn(&self, connection_trn: &str) -> Result<AuthContext> {
         if let Some(store) = &self.store {
-            // Try read real connection from store
-            if let Some(conn) = store
-                .get(connection_trn)
-                .await
-                .map_err(|e| OpenApiToolError::database(e.to_string()))?
+            // Refresh the stored token if it's expiring, then read it back. `refresh_if_expiring`
+            // takes care of single-flight semantics across concurrent callers; we only need to
+            // supply the provider-specific refresh step.
+            match refresh_if_expiring(
+                store.as_ref(),
+                connection_trn,
+                TOKEN_REFRESH_SKEW,
+                Self::refresh_via_provider,
+            )
+            .await
             {
-                let mut ctx = AuthContext::new(
-                    conn.access_token.clone(),
-                    conn.token_type.clone(),
-                    conn.trn.provider.clone(),
-                );
-                if let Some(exp) = conn.expires_at {
-                    ctx = ctx.with_expires_at(exp);
+                Ok(conn) => return Ok(Self::connection_to_auth_context(&conn)),
+                Err(_) => {
+                    // Connection not found, no refresh_token on file, or the provider call
+                    // failed: fall back to whatever is currently stored rather than erroring
+                    // out the whole action.
+                    if let Some(conn) = store
+                        .get(connection_trn)
+                        .await
+                        .map_err(|e| OpenApiToolError::database(e.to_string()))?
+                    {
+                        return Ok(Self::connection_to_auth_context(&conn));
+                    }
                 }
-                return Ok(ctx);
             }
         }
         // Fallback: mock
@@ -278,6 +293,90 @@ impl AuthAdapter {
         .with_expires_at(chrono::Utc::now() + chrono::Duration::hours(1)))
     }
 
+    fn connection_to_auth_context(conn: &Connection) -> AuthContext {
+        let mut ctx = AuthContext::new(
+            conn.access_token.clone(),
+            conn.token_type.clone(),
+            conn.trn.provider.clone(),
+        );
+        if let Some(exp) = conn.expires_at {
+            ctx = ctx.with_expires_at(exp);
+        }
+        ctx
+    }
+
+    /// Exchange `current`'s `refresh_token` for a new access token via the provider's token
+    /// endpoint, the `refresher` callback handed to [`refresh_if_expiring`]. The token endpoint
+    /// and client credentials are read from `current.extra`, the same JSON blob
+    /// `oauth2::device::poll_device_auth` stashes the raw token response into, since `Connection`
+    /// has no dedicated fields for them.
+    async fn refresh_via_provider(current: Connection) -> anyhow::Result<Connection> {
+        let refresh_token = current
+            .refresh_token
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("connection has no refresh_token on file"))?;
+        let token_url = current
+            .extra
+            .get("token_url")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("connection is missing token_url, cannot refresh"))?;
+        let client_id = current
+            .extra
+            .get("client_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("connection is missing client_id, cannot refresh"))?;
+        let client_secret = current.extra.get("client_secret").and_then(|v| v.as_str());
+
+        let mut form = vec![
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token.as_str()),
+            ("client_id", client_id),
+        ];
+        if let Some(cs) = client_secret {
+            form.push(("client_secret", cs));
+        }
+
+        let resp = reqwest::Client::new().post(token_url).form(&form).send().await?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!("token refresh failed: {} - {}", status, body));
+        }
+
+        let token_json: Value = resp.json().await?;
+        let access_token = token_json
+            .get("access_token")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("missing access_token"))?;
+
+        let mut refreshed = current.clone();
+        refreshed.update_access_token(access_token);
+        let new_refresh_token =
+            token_json.get("refresh_token").and_then(|v| v.as_str()).map(str::to_string);
+        if new_refresh_token.is_some() {
+            refreshed.update_refresh_token(new_refresh_token);
+        }
+        if let Some(token_type) = token_json.get("token_type").and_then(|v| v.as_str()) {
+            refreshed.token_type = token_type.to_string();
+        }
+        refreshed.expires_at = token_json
+            .get("expires_in")
+            .and_then(|v| v.as_i64())
+            .map(|secs| chrono::Utc::now() + chrono::Duration::seconds(secs));
+        // Merge the token response into `extra` so the token endpoint and client credentials stay
+        // on file for the next refresh.
+        refreshed.extra = match (current.extra.clone(), token_json) {
+            (Value::Object(mut stored), Value::Object(fresh)) => {
+                stored.extend(fresh);
+                Value::Object(stored)
+            }
+            (_, fresh) => fresh,
+        };
+        refreshed.updated_at = chrono::Utc::now();
+
+        Ok(refreshed)
+    }
+
     /// Get authentication context for an action (by TRN)
     pub async fn get_auth_for_action(&self, auth_config: &AuthConfig) -> Result<AuthContext> {
         self.get_auth_context_by_trn(&auth_config.connection_trn)
@@ -1,14 +1,19 @@
-use serde_json::{json, Value, Map};
-use std::fs;
+use serde_json::{json, Value};
 
 use super::auth::AuthContext;
 use super::expression_engine::ExpressionContext;
 use super::models::{Action, ActionExecutionContext};
+use super::secrets::{extract_secret_key_refs, SecretProvider};
 
-pub fn build_expression_context(
+/// Builds the expression context used to evaluate injection mappings and `x-transform-pre`.
+/// `secrets` resolves only the `vars.secrets.<key>` references actually present in `action`'s
+/// mapping/transform expressions; a resolution failure is swallowed and `secrets` is simply
+/// omitted from the context, matching the previous file-based behavior of failing open.
+pub async fn build_expression_context(
     auth: &AuthContext,
     action: &Action,
     exec: &ActionExecutionContext,
+    secrets: &dyn SecretProvider,
 ) -> ExpressionContext {
     let mut ctx = json!({
         "action": {
@@ -30,10 +35,15 @@ pub fn build_expression_context(
             o.insert("body".to_string(), body.clone());
         }
     }
-    // Inject secrets from OPENACT_SECRETS_FILE if present
-    if let Some(secrets) = load_secrets_from_env_file() {
-        if let Value::Object(ref mut o) = ctx {
-            o.insert("secrets".to_string(), Value::Object(secrets));
+    // Inject only the secrets referenced by this action's mapping/transform expressions.
+    let keys = referenced_secret_keys(action);
+    if !keys.is_empty() {
+        if let Ok(resolved) = secrets.fetch(&keys).await {
+            if !resolved.is_empty() {
+                if let Value::Object(ref mut o) = ctx {
+                    o.insert("secrets".to_string(), Value::Object(resolved));
+                }
+            }
         }
     }
 
@@ -44,21 +54,26 @@ pub fn build_expression_context(
     }
 }
 
-fn load_secrets_from_env_file() -> Option<Map<String, Value>> {
-    let path = std::env::var("OPENACT_SECRETS_FILE").ok()?;
-    let content = fs::read_to_string(&path).ok()?;
-    if path.ends_with(".json") {
-        if let Ok(v) = serde_json::from_str::<Value>(&content) {
-            if let Value::Object(obj) = v { return Some(obj); }
-        }
-    } else {
-        if let Ok(v) = serde_yaml::from_str::<serde_yaml::Value>(&content) {
-            if let Ok(j) = serde_json::to_value(v) {
-                if let Value::Object(obj) = j { return Some(obj); }
+/// Collects the distinct `vars.secrets.<key>` references from an action's injection mapping and
+/// `x-transform-pre` entries.
+fn referenced_secret_keys(action: &Action) -> Vec<String> {
+    let mut keys = Vec::new();
+    if let Some(auth_cfg) = &action.auth_config {
+        extract_secret_key_refs(&auth_cfg.injection.mapping, &mut keys);
+    }
+    if let Some(pre_arr) = action
+        .extensions
+        .get("x-transform-pre")
+        .and_then(|v| v.as_array())
+    {
+        for item in pre_arr {
+            if let Some(s) = item.as_str() {
+                extract_secret_key_refs(s, &mut keys);
+            } else if let Ok(s) = serde_json::to_string(item) {
+                extract_secret_key_refs(&s, &mut keys);
             }
         }
     }
-    None
+    keys
 }
 
-
@@ -9,6 +9,7 @@ pub mod extensions;
 pub mod auth;
 pub mod expression_engine;
 pub mod expression_context;
+pub mod secrets;
 
 pub use parser::ActionParser;
 pub use models::*;
@@ -17,3 +18,4 @@ pub use extensions::*;
 pub use auth::*;
 pub use expression_engine::*;
 pub use expression_context::*;
+pub use secrets::*;
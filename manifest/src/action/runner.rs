@@ -5,6 +5,7 @@ use super::auth::{AuthAdapter, AuthContext, RefreshWhen};
 use super::expression_context::build_expression_context;
 use super::expression_engine::evaluate_mapping;
 use super::models::*;
+use super::secrets::{default_secret_provider, SecretProvider};
 use crate::utils::error::{OpenApiToolError, Result};
 use bumpalo::Bump;
 use jsonata_rs::JsonAta;
@@ -28,6 +29,8 @@ pub struct ActionRunner {
     /// Tenant identifier
     #[allow(dead_code)]
     tenant: String,
+    /// Resolves `vars.secrets.*` references in mapping/transform expressions
+    secrets_provider: Arc<dyn SecretProvider>,
 }
 
 impl ActionRunner {
@@ -38,6 +41,7 @@ impl ActionRunner {
             max_retries: 3,
             auth_adapter: None,
             tenant: "default".to_string(),
+            secrets_provider: default_secret_provider(),
         }
     }
 
@@ -48,6 +52,7 @@ impl ActionRunner {
             max_retries: 3,
             auth_adapter: None,
             tenant,
+            secrets_provider: default_secret_provider(),
         }
     }
 
@@ -56,6 +61,12 @@ impl ActionRunner {
         self.auth_adapter = Some(auth_adapter);
     }
 
+    /// Override the secret provider (defaults to `default_secret_provider()`, selected via
+    /// `OPENACT_SECRETS_PROVIDER`)
+    pub fn set_secrets_provider(&mut self, secrets_provider: Arc<dyn SecretProvider>) {
+        self.secrets_provider = secrets_provider;
+    }
+
     /// Create a new action runner with custom timeout
     pub fn with_timeout(timeout_ms: u64) -> Self {
         Self {
@@ -63,6 +74,7 @@ impl ActionRunner {
             max_retries: 3,
             auth_adapter: None,
             tenant: "default".to_string(),
+            secrets_provider: default_secret_provider(),
         }
     }
 
@@ -166,7 +178,9 @@ impl ActionRunner {
 
             // Evaluate injection mapping if provided
             let mapping = &auth_cfg.injection.mapping;
-            let expr_ctx = build_expression_context(auth, action, &context);
+            let expr_ctx =
+                build_expression_context(auth, action, &context, self.secrets_provider.as_ref())
+                    .await;
             if !mapping.trim().is_empty() {
                 // Diagnostics: enumerate required $vars.secrets.* keys and check availability
                 if let Some(missing) = missing_secret_keys_for_mapping(mapping, &expr_ctx) {
@@ -230,7 +244,9 @@ impl ActionRunner {
                 )),
                 action,
                 &context,
-            );
+                self.secrets_provider.as_ref(),
+            )
+            .await;
             for item in pre_arr {
                 let evaluated_map = if let Some(s) = item.as_str() {
                     evaluate_mapping(s, &expr_ctx).ok()
@@ -1693,33 +1709,8 @@ fn missing_secret_keys_for_mapping(
     mapping: &str,
     ctx: &super::expression_engine::ExpressionContext,
 ) -> Option<Vec<String>> {
-    // naive scan for vars.secrets.<key>
     let mut keys: Vec<String> = Vec::new();
-    let needle = "vars.secrets.";
-    let bytes = mapping.as_bytes();
-    let mut i: usize = 0;
-    while let Some(pos) = mapping[i..].find(needle) {
-        let start = i + pos + needle.len();
-        let mut end = start;
-        while end < bytes.len() {
-            let c = bytes[end] as char;
-            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
-                end += 1;
-            } else {
-                break;
-            }
-        }
-        if end > start {
-            let key = mapping[start..end].to_string();
-            if !keys.iter().any(|k| k == &key) {
-                keys.push(key);
-            }
-        }
-        i = end;
-        if i >= bytes.len() {
-            break;
-        }
-    }
+    super::secrets::extract_secret_key_refs(mapping, &mut keys);
     if keys.is_empty() {
         return Some(Vec::new());
     }
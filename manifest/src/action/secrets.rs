@@ -0,0 +1,259 @@
+// Pluggable secret resolution for `x-transform-pre` / injection mapping expressions
+//
+// `build_expression_context` injects whatever a `SecretProvider` resolves under `vars.secrets`.
+// The default provider reads `OPENACT_SECRETS_FILE`, matching the previous hardcoded behavior;
+// `EnvSecretProvider` and `RemoteSecretProvider` let operators point at environment variables or
+// a credential broker (Vault / AWS Secrets Manager style) instead, without touching call sites.
+
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+use crate::utils::error::Result;
+
+/// Resolves secret values referenced as `vars.secrets.<key>` in action mapping expressions.
+#[async_trait]
+pub trait SecretProvider: Send + Sync {
+    /// Resolve `keys`, returning only the ones that could be found. Implementations should treat
+    /// a missing key as "omit it" rather than an error; `build_expression_context` has no way to
+    /// stop execution on a partially-resolved secrets map.
+    async fn fetch(&self, keys: &[String]) -> Result<Map<String, Value>>;
+}
+
+/// Reads the whole `OPENACT_SECRETS_FILE` (JSON or YAML) and filters it down to `keys`. This is
+/// the provider used when nothing else is configured, matching the tool's original behavior.
+#[derive(Debug, Clone, Default)]
+pub struct FileSecretProvider {
+    path: Option<String>,
+}
+
+impl FileSecretProvider {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: Some(path.into()),
+        }
+    }
+
+    /// Reads the path from `OPENACT_SECRETS_FILE` at construction time.
+    pub fn from_env() -> Self {
+        Self {
+            path: std::env::var("OPENACT_SECRETS_FILE").ok(),
+        }
+    }
+
+    fn read_all(&self) -> Option<Map<String, Value>> {
+        let path = self.path.as_ref()?;
+        let content = std::fs::read_to_string(path).ok()?;
+        if path.ends_with(".json") {
+            if let Ok(Value::Object(obj)) = serde_json::from_str::<Value>(&content) {
+                return Some(obj);
+            }
+        } else if let Ok(v) = serde_yaml::from_str::<serde_yaml::Value>(&content) {
+            if let Ok(Value::Object(obj)) = serde_json::to_value(v) {
+                return Some(obj);
+            }
+        }
+        None
+    }
+}
+
+#[async_trait]
+impl SecretProvider for FileSecretProvider {
+    async fn fetch(&self, keys: &[String]) -> Result<Map<String, Value>> {
+        let Some(all) = self.read_all() else {
+            return Ok(Map::new());
+        };
+        if keys.is_empty() {
+            return Ok(all);
+        }
+        Ok(keys
+            .iter()
+            .filter_map(|k| all.get(k).map(|v| (k.clone(), v.clone())))
+            .collect())
+    }
+}
+
+/// Resolves each key from an environment variable, uppercased with `-` replaced by `_`
+/// (`stripe-api-key` -> `STRIPE_API_KEY`), mirroring `env_var_candidates_for_key` in `runner.rs`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvSecretProvider;
+
+impl EnvSecretProvider {
+    fn env_var_name(key: &str) -> String {
+        key.replace('-', "_").to_uppercase()
+    }
+}
+
+#[async_trait]
+impl SecretProvider for EnvSecretProvider {
+    async fn fetch(&self, keys: &[String]) -> Result<Map<String, Value>> {
+        let mut out = Map::new();
+        for key in keys {
+            if let Ok(v) = std::env::var(Self::env_var_name(key)) {
+                out.insert(key.clone(), Value::String(v));
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[derive(Clone)]
+struct CachedSecret {
+    value: Value,
+    fetched_at: Instant,
+}
+
+/// Fetches secrets from a remote broker over HTTP (Vault / AWS Secrets Manager style: a base URL
+/// plus a per-key path, bearer-authenticated). Resolved values are cached for `ttl`; a `fetch`
+/// call returns a cached value immediately (even if stale) and, once it is older than `ttl`, kicks
+/// off a background refresh, so `build_expression_context` only blocks on the network for a key
+/// that is not cached yet (including a key the broker did not resolve last time).
+pub struct RemoteSecretProvider {
+    base_url: String,
+    token: Option<String>,
+    ttl: Duration,
+    client: reqwest::Client,
+    cache: Arc<RwLock<HashMap<String, CachedSecret>>>,
+}
+
+impl RemoteSecretProvider {
+    pub fn new(base_url: impl Into<String>, token: Option<String>, ttl: Duration) -> Self {
+        Self {
+            base_url: base_url.into(),
+            token,
+            ttl,
+            client: reqwest::Client::new(),
+            cache: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    async fn fetch_one(&self, key: &str) -> Option<Value> {
+        let url = format!("{}/v1/secrets/{}", self.base_url.trim_end_matches('/'), key);
+        let mut req = self.client.get(&url);
+        if let Some(token) = &self.token {
+            req = req.bearer_auth(token);
+        }
+        let resp = req.send().await.ok()?;
+        if !resp.status().is_success() {
+            return None;
+        }
+        resp.json::<Value>().await.ok()
+    }
+
+    fn spawn_refresh(&self, key: String) {
+        let client = self.client.clone();
+        let base_url = self.base_url.clone();
+        let token = self.token.clone();
+        let cache = self.cache.clone();
+        tokio::spawn(async move {
+            let url = format!("{}/v1/secrets/{}", base_url.trim_end_matches('/'), key);
+            let mut req = client.get(&url);
+            if let Some(token) = &token {
+                req = req.bearer_auth(token);
+            }
+            if let Ok(resp) = req.send().await {
+                if resp.status().is_success() {
+                    if let Ok(value) = resp.json::<Value>().await {
+                        cache.write().await.insert(
+                            key,
+                            CachedSecret {
+                                value,
+                                fetched_at: Instant::now(),
+                            },
+                        );
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl SecretProvider for RemoteSecretProvider {
+    async fn fetch(&self, keys: &[String]) -> Result<Map<String, Value>> {
+        let mut out = Map::new();
+        for key in keys {
+            let cached = self.cache.read().await.get(key).cloned();
+            match cached {
+                Some(entry) if entry.fetched_at.elapsed() < self.ttl => {
+                    out.insert(key.clone(), entry.value);
+                }
+                Some(entry) => {
+                    // Stale but present: serve it now, refresh in the background.
+                    out.insert(key.clone(), entry.value);
+                    self.spawn_refresh(key.clone());
+                }
+                None => {
+                    // Cold cache: this first lookup has to block on the network.
+                    if let Some(value) = self.fetch_one(key).await {
+                        self.cache.write().await.insert(
+                            key.clone(),
+                            CachedSecret {
+                                value: value.clone(),
+                                fetched_at: Instant::now(),
+                            },
+                        );
+                        out.insert(key.clone(), value);
+                    }
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Picks a provider based on `OPENACT_SECRETS_PROVIDER` (`file` (default) | `env` | `remote`).
+/// The `remote` provider reads `OPENACT_SECRETS_REMOTE_URL` (required), `OPENACT_SECRETS_REMOTE_TOKEN`
+/// (optional bearer token) and `OPENACT_SECRETS_TTL_SECS` (default 60).
+pub fn default_secret_provider() -> Arc<dyn SecretProvider> {
+    match std::env::var("OPENACT_SECRETS_PROVIDER").as_deref() {
+        Ok("env") => Arc::new(EnvSecretProvider),
+        Ok("remote") => {
+            let base_url = std::env::var("OPENACT_SECRETS_REMOTE_URL").unwrap_or_default();
+            let token = std::env::var("OPENACT_SECRETS_REMOTE_TOKEN").ok();
+            let ttl_secs = std::env::var("OPENACT_SECRETS_TTL_SECS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(60);
+            Arc::new(RemoteSecretProvider::new(
+                base_url,
+                token,
+                Duration::from_secs(ttl_secs),
+            ))
+        }
+        _ => Arc::new(FileSecretProvider::from_env()),
+    }
+}
+
+/// Scans `text` for `vars.secrets.<key>` references (same pattern matched by
+/// `missing_secret_keys_for_mapping` in `runner.rs`) and returns the distinct keys found.
+pub fn extract_secret_key_refs(text: &str, out: &mut Vec<String>) {
+    let needle = "vars.secrets.";
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while let Some(pos) = text[i..].find(needle) {
+        let start = i + pos + needle.len();
+        let mut end = start;
+        while end < bytes.len() {
+            let c = bytes[end] as char;
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                end += 1;
+            } else {
+                break;
+            }
+        }
+        if end > start {
+            let key = text[start..end].to_string();
+            if !out.contains(&key) {
+                out.push(key);
+            }
+        }
+        i = end.max(i + pos + needle.len());
+        if i >= bytes.len() {
+            break;
+        }
+    }
+}
@@ -312,6 +312,47 @@ impl TaskUpsertRequest {
     }
 }
 
+/// Bulk config import request: connections and/or tasks to upsert, mirroring the CLI's local
+/// `config import` path (either field may be omitted/empty).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ConfigImportRequest {
+    #[serde(default)]
+    pub connections: Vec<crate::models::ConnectionConfig>,
+    #[serde(default)]
+    pub tasks: Vec<crate::models::TaskConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ConfigImportResponse {
+    pub connections_imported: usize,
+    pub tasks_imported: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ConfigExportResponse {
+    pub connections: Vec<crate::models::ConnectionConfig>,
+    pub tasks: Vec<crate::models::TaskConfig>,
+}
+
+/// Bind OAuth credentials (identified by `auth_trn`) to a connection's `auth_ref`, mirroring
+/// the CLI's local `oauth bind` path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct OauthBindRequest {
+    pub connection_trn: String,
+    pub auth_trn: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct OauthBindResponse {
+    pub connection_trn: String,
+    pub auth_ref: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
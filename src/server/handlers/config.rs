@@ -0,0 +1,94 @@
+#![cfg(feature = "server")]
+
+use crate::app::service::OpenActService;
+use crate::interface::dto::{ConfigExportResponse, ConfigImportRequest, ConfigImportResponse};
+use crate::interface::error::helpers;
+use axum::{
+    Json,
+    extract::{Query, State},
+    response::IntoResponse,
+};
+use std::collections::HashMap;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/config/import",
+    tag = "config",
+    operation_id = "config_import",
+    summary = "Import connections and tasks",
+    description = "Bulk-import connections and/or tasks, the server-side counterpart of the CLI's `config import` command",
+    request_body = ConfigImportRequest,
+    responses(
+        (status = 200, description = "Import summary", body = ConfigImportResponse),
+        (status = 400, description = "Invalid connection or task data", body = crate::interface::error::ApiError),
+        (status = 500, description = "Internal server error", body = crate::interface::error::ApiError)
+    )
+))]
+pub async fn import(
+    State(svc): State<OpenActService>,
+    Json(req): Json<ConfigImportRequest>,
+) -> impl IntoResponse {
+    match svc
+        .import_configurations(req.connections, req.tasks)
+        .await
+    {
+        Ok((connections_imported, tasks_imported)) => Json(ConfigImportResponse {
+            connections_imported,
+            tasks_imported,
+        })
+        .into_response(),
+        Err(e) => helpers::validation_error("invalid_input", e.to_string()).into_response(),
+    }
+}
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/api/v1/config/export",
+    tag = "config",
+    operation_id = "config_export",
+    summary = "Export connections and tasks",
+    description = "Export all connections and tasks, the server-side counterpart of the CLI's `config export` command. `?format=yaml` returns a YAML document instead of the default JSON.",
+    params(
+        ("format" = Option<String>, Query, description = "Output format: json (default) or yaml")
+    ),
+    responses(
+        (status = 200, description = "Connections and tasks", body = ConfigExportResponse),
+        (status = 400, description = "Unsupported format", body = crate::interface::error::ApiError),
+        (status = 500, description = "Internal server error", body = crate::interface::error::ApiError)
+    )
+))]
+pub async fn export(
+    State(svc): State<OpenActService>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    use axum::body::Body;
+    use axum::http::{StatusCode, header};
+    use axum::response::Response;
+
+    let format = params.get("format").map(|s| s.as_str()).unwrap_or("json");
+
+    let (connections, tasks) = match svc.export_configurations().await {
+        Ok(v) => v,
+        Err(e) => return helpers::storage_error(e.to_string()).into_response(),
+    };
+
+    match format {
+        "json" => Json(ConfigExportResponse { connections, tasks }).into_response(),
+        "yaml" => {
+            let obj = serde_json::json!({ "connections": connections, "tasks": tasks });
+            match serde_yaml::to_string(&obj) {
+                Ok(yaml) => Response::builder()
+                    .status(StatusCode::OK)
+                    .header(header::CONTENT_TYPE, "application/yaml; charset=utf-8")
+                    .body(Body::from(yaml))
+                    .unwrap(),
+                Err(e) => helpers::storage_error(e.to_string()).into_response(),
+            }
+        }
+        other => helpers::validation_error(
+            "invalid_input",
+            format!("unsupported format: {}", other),
+        )
+        .into_response(),
+    }
+}
@@ -5,6 +5,8 @@ pub mod tasks;
 pub mod execute;
 pub mod system;
 pub mod connect;
+pub mod config;
+pub mod oauth;
 
 #[cfg(test)]
 mod tests;
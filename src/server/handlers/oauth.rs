@@ -0,0 +1,44 @@
+#![cfg(feature = "server")]
+
+use crate::app::service::OpenActService;
+use crate::interface::dto::{OauthBindRequest, OauthBindResponse};
+use crate::interface::error::helpers;
+use axum::{Json, extract::State, response::IntoResponse};
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/oauth/bind",
+    tag = "oauth",
+    operation_id = "oauth_bind",
+    summary = "Bind OAuth credentials to a connection",
+    description = "Set a connection's `auth_ref` to an already-obtained OAuth auth TRN, the server-side counterpart of the CLI's `oauth bind` command",
+    request_body = OauthBindRequest,
+    responses(
+        (status = 200, description = "Connection bound", body = OauthBindResponse),
+        (status = 404, description = "Connection not found", body = crate::interface::error::ApiError),
+        (status = 500, description = "Internal server error", body = crate::interface::error::ApiError)
+    )
+))]
+pub async fn bind(
+    State(svc): State<OpenActService>,
+    Json(req): Json<OauthBindRequest>,
+) -> impl IntoResponse {
+    let repo = svc.database().connection_repository();
+
+    let mut conn = match repo.get_by_trn(&req.connection_trn).await {
+        Ok(Some(c)) => c,
+        Ok(None) => return helpers::not_found_error("connection").into_response(),
+        Err(e) => return helpers::storage_error(e.to_string()).into_response(),
+    };
+
+    conn.auth_ref = Some(req.auth_trn.clone());
+
+    match repo.upsert(&conn).await {
+        Ok(()) => Json(OauthBindResponse {
+            connection_trn: req.connection_trn,
+            auth_ref: req.auth_trn,
+        })
+        .into_response(),
+        Err(e) => helpers::storage_error(e.to_string()).into_response(),
+    }
+}
@@ -2,9 +2,10 @@
 
 use crate::app::service::OpenActService;
 use crate::interface::error::helpers;
-use axum::{Json, extract::State, response::IntoResponse};
+use axum::{Json, extract::{Query, State}, response::IntoResponse};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[cfg(feature = "openapi")]
 use utoipa::ToSchema;
@@ -451,10 +452,13 @@ pub async fn cleanup(State(svc): State<OpenActService>) -> impl IntoResponse {
     path = "/api/v1/system/metrics",
     tag = "system",
     operation_id = "system_get_metrics",
-    summary = "Prometheus metrics",
-    description = "Get system metrics in Prometheus format for monitoring and alerting",
+    summary = "System metrics",
+    description = "Get system metrics, in Prometheus format by default or as JSON via ?format=json, for monitoring and alerting",
+    params(
+        ("format" = Option<String>, Query, description = "Output format: prometheus (default) or json")
+    ),
     responses(
-        (status = 200, description = "Metrics in Prometheus format", content_type = "text/plain"),
+        (status = 200, description = "Metrics in Prometheus or JSON format", content_type = "text/plain"),
         (status = 500, description = "Internal server error", body = crate::interface::error::ApiError)
     ),
     security(
@@ -463,23 +467,48 @@ pub async fn cleanup(State(svc): State<OpenActService>) -> impl IntoResponse {
         ("api_key" = [])
     )
 ))]
-/// Prometheus metrics endpoint
-pub async fn metrics() -> impl IntoResponse {
+/// System metrics endpoint: Prometheus exposition format by default, or JSON via `?format=json`
+pub async fn metrics(
+    State(svc): State<OpenActService>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
     use axum::response::Response;
     use axum::body::Body;
     use axum::http::{StatusCode, header};
-    
-    match crate::observability::metrics::export_prometheus() {
-        Ok(metrics_text) => {
-            Response::builder()
+
+    match params.get("format").map(|s| s.as_str()) {
+        Some("json") => {
+            let storage = svc.stats().await;
+            let caches = svc.cache_stats().await;
+            match (storage, caches) {
+                (Ok(s), Ok(c)) => {
+                    let cp = crate::executor::client_pool::get_stats();
+                    Json(serde_json::json!({
+                        "storage": s,
+                        "caches": c,
+                        "client_pool": {
+                            "hits": cp.hits,
+                            "builds": cp.builds,
+                            "evictions": cp.evictions,
+                            "size": cp.size,
+                            "capacity": cp.capacity
+                        }
+                    }))
+                    .into_response()
+                }
+                (Err(e), _) | (_, Err(e)) => helpers::storage_error(e.to_string()).into_response(),
+            }
+        }
+        _ => match crate::observability::metrics::export_prometheus() {
+            Ok(metrics_text) => Response::builder()
                 .status(StatusCode::OK)
                 .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
                 .body(Body::from(metrics_text))
-                .unwrap()
-        }
-        Err(e) => {
-            let error = helpers::storage_error(format!("Failed to export metrics: {}", e));
-            error.into_response()
-        }
+                .unwrap(),
+            Err(e) => {
+                let error = helpers::storage_error(format!("Failed to export metrics: {}", e));
+                error.into_response()
+            }
+        },
     }
 }
@@ -82,6 +82,30 @@ pub fn core_api_router_with_state(service: OpenActService) -> Router {
             "/api/v1/system/cleanup",
             post(crate::server::handlers::system::cleanup),
         )
+        .route(
+            "/api/v1/config/import",
+            post(crate::server::handlers::config::import),
+        )
+        .route(
+            "/api/v1/config/export",
+            get(crate::server::handlers::config::export),
+        )
+        .route(
+            "/api/v1/oauth/bind",
+            post(crate::server::handlers::oauth::bind),
+        );
+
+    // Prometheus/JSON metrics for the System CLI's `--server` proxy mode, separate from the
+    // plain-text `/metrics` observability endpoint below.
+    #[cfg(feature = "metrics")]
+    {
+        router = router.route(
+            "/api/v1/system/metrics",
+            get(crate::server::handlers::system::metrics),
+        );
+    }
+
+    router = router
         // Observability endpoints
         .route(
             "/health",